@@ -51,9 +51,31 @@ fn push_back(c: &mut Criterion) {
     group.finish();
 }
 
+fn build_clear_cycles(retain: bool) {
+    let mut list = LinkedList::new();
+    for _ in 0..100 {
+        for i in 0..1_000 {
+            list.push_back(i);
+        }
+        if retain {
+            list.clear_retaining_allocations();
+        } else {
+            list = LinkedList::new();
+        }
+    }
+    black_box(list);
+}
+
+fn build_clear(c: &mut Criterion) {
+    let mut group = c.benchmark_group("build_clear");
+    group.bench_function("free_nodes", |b| b.iter(|| build_clear_cycles(false)));
+    group.bench_function("retain_nodes", |b| b.iter(|| build_clear_cycles(true)));
+    group.finish();
+}
+
 criterion_group!(
     name = benches;
     config = Criterion::default();
-    targets = bench_list_length, push_back
+    targets = bench_list_length, push_back, build_clear
 );
 criterion_main!(benches);
@@ -1,6 +1,6 @@
-use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
 use datastructures::linked_list::LinkedList;
-use datastructures::packed_linked_list::PackedLinkedList;
+use datastructures::packed_linked_list::{InsertPolicy, PackedLinkedList};
 
 fn create_random_list(size: usize) -> LinkedList<i32> {
     let mut number = 837582573;
@@ -35,6 +35,54 @@ fn create_random_packed_list_128(size: usize) -> PackedLinkedList<i32, 128> {
     list
 }
 
+fn create_random_vec(size: usize) -> Vec<i32> {
+    let mut number = 837582573;
+    let mut vec = Vec::with_capacity(size);
+    for _ in 0..size {
+        // just random stuff I cam up with, does not need to be actually random
+        number = (number ^ (number << 5)) >> 3;
+        vec.push(number);
+    }
+    vec
+}
+
+fn push_loop<const COUNT: usize>(values: &[i32]) -> PackedLinkedList<i32, COUNT> {
+    let mut list = PackedLinkedList::new();
+    for value in values {
+        list.push_back(*value);
+    }
+    list
+}
+
+fn bulk_fill<const COUNT: usize>(values: &[i32]) -> PackedLinkedList<i32, COUNT> {
+    // `FromIterator` fills every node in one go
+    values.iter().copied().collect()
+}
+
+fn from_vec(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_vec");
+    for i in [100, 1_000_000].iter() {
+        let values = create_random_vec(*i);
+        group.bench_with_input(BenchmarkId::new("push_loop_16", i), &values, |b, values| {
+            b.iter(|| push_loop::<16>(values))
+        });
+        group.bench_with_input(BenchmarkId::new("bulk_fill_16", i), &values, |b, values| {
+            b.iter(|| bulk_fill::<16>(values))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("push_loop_128", i),
+            &values,
+            |b, values| b.iter(|| push_loop::<128>(values)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("bulk_fill_128", i),
+            &values,
+            |b, values| b.iter(|| bulk_fill::<128>(values)),
+        );
+    }
+    group.finish();
+}
+
 fn push_back(c: &mut Criterion) {
     let mut group = c.benchmark_group("push_back");
     for i in [100, 1_000_000].iter() {
@@ -65,9 +113,99 @@ fn iterate(c: &mut Criterion) {
     c.bench_function("iterate", |b| b.iter(|| do_iterate(&list)));
 }
 
+fn pop_front(c: &mut Criterion) {
+    // popping from the front only moves the start of the node, so this should not depend on COUNT
+    let mut group = c.benchmark_group("pop_front");
+    group.bench_function("packed_list_16", |b| {
+        b.iter_batched(
+            || create_random_packed_list_16(100_000),
+            |mut list| {
+                while let Some(item) = list.pop_front() {
+                    black_box(item);
+                }
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("packed_list_128", |b| {
+        b.iter_batched(
+            || create_random_packed_list_128(100_000),
+            |mut list| {
+                while let Some(item) = list.pop_front() {
+                    black_box(item);
+                }
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+fn churn(mut list: PackedLinkedList<i32, 16>) {
+    // every push allocates a node and every pop frees it again, unless the node is pooled
+    for i in 0..100_000 {
+        list.push_back(i);
+        black_box(list.pop_front());
+    }
+}
+
+fn push_pop_churn(c: &mut Criterion) {
+    let mut group = c.benchmark_group("push_pop_churn");
+    group.bench_function("without_pool", |b| {
+        b.iter(|| churn(PackedLinkedList::new()))
+    });
+    group.bench_function("with_pool", |b| {
+        b.iter(|| churn(PackedLinkedList::with_node_pool()))
+    });
+    group.finish();
+}
+
+fn insert_in_middle(policy: InsertPolicy) {
+    let mut list = create_random_packed_list_16(1_000);
+    list.set_insert_policy(policy);
+    let mut cursor = list.cursor_mut_front();
+    for _ in 0..500 {
+        cursor.move_next();
+    }
+    // the cursor doesn't move, so all values are inserted at the same place
+    for i in 0..10_000 {
+        cursor.insert_after(i);
+    }
+    black_box(list);
+}
+
+fn insert_policy(c: &mut Criterion) {
+    let mut group = c.benchmark_group("insert_policy");
+    for policy in [
+        InsertPolicy::AlwaysSplit,
+        InsertPolicy::PreferNext,
+        InsertPolicy::BalancedSplit,
+    ]
+    .iter()
+    {
+        group.bench_function(format!("{:?}", policy), |b| {
+            b.iter(|| insert_in_middle(*policy))
+        });
+    }
+    group.finish();
+}
+
+fn eq(c: &mut Criterion) {
+    let mut group = c.benchmark_group("eq");
+    let a = create_random_packed_list_128(1_000_000);
+    let b = a.clone();
+    group.bench_function("naive_128", |bench| {
+        bench.iter(|| black_box(black_box(&a) == black_box(&b)))
+    });
+    group.bench_function("eq_fast_128", |bench| {
+        bench.iter(|| black_box(black_box(&a).eq_fast(black_box(&b))))
+    });
+    group.finish();
+}
+
 criterion_group!(
     name = benches;
     config = Criterion::default();
-    targets = iterate, push_back
+    targets = iterate, push_back, from_vec, pop_front, push_pop_churn, insert_policy, eq
 );
 criterion_main!(benches);
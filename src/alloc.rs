@@ -0,0 +1,13 @@
+use std::collections::TryReserveError;
+use std::ptr::NonNull;
+
+/// Boxes the element like `Box::new`, but returns an error instead of aborting if the allocation fails
+pub(crate) fn try_allocate_nonnull<T>(element: T) -> Result<NonNull<T>, TryReserveError> {
+    let mut vec = Vec::new();
+    vec.try_reserve_exact(1)?;
+    vec.push(element);
+    // a boxed slice with a single element has the same layout as a box of the element
+    let boxed = vec.into_boxed_slice();
+    // SAFETY: box is always non-null
+    Ok(unsafe { NonNull::new_unchecked(Box::into_raw(boxed) as *mut T) })
+}
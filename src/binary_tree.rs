@@ -1,12 +1,27 @@
+use crate::linked_list::LinkedList;
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::iter::Sum;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::Add;
+use std::ptr;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct BinaryTree<T>(Node<T>);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BinaryTree<T>(Option<Node<T>>);
 
+/// With the `serde` feature, a node is serialized recursively as `{ "value": .., "left": .., "right": .. }`
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Node<T> {
+    #[cfg_attr(feature = "serde", serde(rename = "left"))]
     lhs: Option<Box<Node<T>>>,
+    #[cfg_attr(feature = "serde", serde(rename = "value"))]
     val: T,
+    #[cfg_attr(feature = "serde", serde(rename = "right"))]
     rhs: Option<Box<Node<T>>>,
 }
 
@@ -22,6 +37,1122 @@ impl<T> Node<T> {
     pub fn leaf(value: T) -> Self {
         Self::new(value, None, None)
     }
+
+    /// Builds a tree of an arbitrary shape from a list of `(value, parent_index, side)` entries
+    ///
+    /// The first entry is the root, its parent index and side are ignored. Every other entry is attached
+    /// as the `side` child of the entry at `parent_index`, which has to come before it in the list.
+    /// The resulting tree does not have to be a valid search tree.
+    ///
+    /// # Panics
+    /// Panics if the shape is empty, a parent index doesn't point to an earlier entry or a child position is
+    /// used twice
+    pub fn from_shape(shape: &[(T, usize, Side)]) -> Self
+    where
+        T: Clone,
+    {
+        assert!(!shape.is_empty(), "a tree needs at least a root");
+        let mut nodes = shape
+            .iter()
+            .map(|(value, _, _)| Some(Node::leaf(value.clone())))
+            .collect::<Vec<_>>();
+
+        // attach the nodes from the back, so that every child already has all of its own children
+        for (index, (_, parent_index, side)) in shape.iter().enumerate().skip(1).rev() {
+            assert!(
+                *parent_index < index,
+                "the parent has to come before the child"
+            );
+            let child = nodes[index].take().map(Box::new);
+            let parent = nodes[*parent_index].as_mut().unwrap();
+            let slot = match side {
+                Side::Left => &mut parent.lhs,
+                Side::Right => &mut parent.rhs,
+            };
+            assert!(slot.is_none(), "the child position is already used");
+            *slot = child;
+        }
+
+        nodes[0].take().unwrap()
+    }
+
+    /// Rearranges the tree so that no node has a left child and the right children form a chain of the values
+    /// in pre-order. The in-order of the flattened tree is the pre-order of the original tree
+    pub fn flatten_to_right(&mut self) {
+        let mut current = self;
+        loop {
+            if let Some(mut lhs) = current.lhs.take() {
+                // the right subtree comes after the last node of the left subtree in pre-order
+                let mut last = &mut *lhs;
+                while last.rhs.is_some() {
+                    last = last.rhs.as_mut().unwrap();
+                }
+                last.rhs = current.rhs.take();
+                current.rhs = Some(lhs);
+            }
+            current = match &mut current.rhs {
+                Some(next) => next,
+                None => break,
+            };
+        }
+    }
+
+    /// Removes all nodes that are more than `max_depth` levels below this node, so that the nodes at depth
+    /// `max_depth` become leaves. A `max_depth` of 0 only keeps this node
+    pub fn truncate_depth(&mut self, max_depth: usize) {
+        let mut stack = vec![(self, 0)];
+        while let Some((node, depth)) = stack.pop() {
+            if depth == max_depth {
+                node.lhs = None;
+                node.rhs = None;
+            } else {
+                let Node { lhs, rhs, .. } = node;
+                stack.extend(
+                    lhs.iter_mut()
+                        .chain(rhs.iter_mut())
+                        .map(|child| (&mut **child, depth + 1)),
+                );
+            }
+        }
+    }
+
+    /// Rotates the tree to the right, so that the left child becomes the root and the old root becomes its right
+    /// child. The in-order of the values is kept. Does nothing if there is no left child
+    pub fn rotate_right(&mut self) {
+        if let Some(mut lhs) = self.lhs.take() {
+            self.lhs = lhs.rhs.take();
+            mem::swap(self, &mut lhs);
+            self.rhs = Some(lhs);
+        }
+    }
+
+    /// Rotates the tree to the left, so that the right child becomes the root and the old root becomes its left
+    /// child. The in-order of the values is kept. Does nothing if there is no right child
+    pub fn rotate_left(&mut self) {
+        if let Some(mut rhs) = self.rhs.take() {
+            self.rhs = rhs.lhs.take();
+            mem::swap(self, &mut rhs);
+            self.lhs = Some(rhs);
+        }
+    }
+
+    /// Shifts the values in in-order to the right by `n` places, wrapping around at the end. The values are
+    /// written back into the existing nodes, so the shape of the tree doesn't change
+    ///
+    /// For a search tree, this breaks the ordering unless `n` is a multiple of the node count
+    pub fn rotate_values_right(&mut self, n: usize)
+    where
+        T: Clone,
+    {
+        let mut values = self.iter_inorder().cloned().collect::<Vec<_>>();
+        let len = values.len();
+        values.rotate_right(n % len);
+        for (slot, value) in self.iter_inorder_mut().zip(values) {
+            *slot = value;
+        }
+    }
+
+    /// Walks the tree depth first, emitting an event when entering and leaving every node
+    pub fn walk(&self) -> Walk<'_, T> {
+        Walk {
+            stack: vec![(self, false)],
+        }
+    }
+
+    /// Returns an iterator over the values in in-order (left subtree, node, right subtree)
+    ///
+    /// The iterator keeps a stack of the nodes on the current path, so it needs O(height) memory,
+    /// see [Node::iter_inorder_morris] for an iterator that gets by in O(1)
+    pub fn iter_inorder(&self) -> InOrder<'_, T> {
+        let mut iter = InOrder { stack: Vec::new() };
+        iter.push_left(self);
+        iter
+    }
+
+    /// Returns an iterator over mutable references to the values in in-order
+    ///
+    /// Changing the values can break the ordering of a search tree, the caller has to make sure that the order
+    /// of the values is kept if the tree is used as one
+    pub fn iter_inorder_mut(&mut self) -> InOrderMut<'_, T> {
+        let mut iter = InOrderMut {
+            stack: Vec::new(),
+            _marker: PhantomData,
+        };
+        iter.push_left(self);
+        iter
+    }
+
+    /// Returns an iterator over the values in in-order that only needs O(1) memory, using a Morris traversal
+    ///
+    /// While iterating, the right child of the in-order predecessor of a node temporarily points back to that
+    /// node, which is why the tree is borrowed mutably. The tree is restored once the iterator is exhausted
+    /// or dropped. If the iterator is leaked, the subtrees of the root are leaked with it
+    pub fn iter_inorder_morris(&mut self) -> InOrderMorris<'_, T> {
+        InOrderMorris::new(self)
+    }
+
+    /// Returns an iterator over the values of the nodes without children, from left to right
+    pub fn leaves(&self) -> impl Iterator<Item = &T> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || loop {
+            let node = stack.pop()?;
+            if node.lhs.is_none() && node.rhs.is_none() {
+                return Some(&node.val);
+            }
+            // push the right child first so that the left one is visited first
+            stack.extend(node.rhs.iter().chain(node.lhs.iter()).map(|child| &**child));
+        })
+    }
+
+    /// Returns the boundary of the tree anti-clockwise, starting at this node: the left boundary from top to
+    /// bottom, then the leaves from left to right and then the right boundary from bottom to top. Every node is
+    /// only contained once
+    ///
+    /// The left boundary is the path from the left child that always goes to the left child if there is one, and
+    /// to the right child otherwise, excluding the leaf at the end. The right boundary is the same mirrored
+    pub fn boundary(&self) -> Vec<&T> {
+        fn is_leaf<T>(node: &Node<T>) -> bool {
+            node.lhs.is_none() && node.rhs.is_none()
+        }
+
+        let mut boundary = vec![&self.val];
+        if is_leaf(self) {
+            return boundary;
+        }
+
+        let mut current = self.lhs.as_deref();
+        while let Some(node) = current.filter(|node| !is_leaf(node)) {
+            boundary.push(&node.val);
+            current = node.lhs.as_deref().or(node.rhs.as_deref());
+        }
+
+        boundary.extend(self.leaves());
+
+        let mut right = Vec::new();
+        let mut current = self.rhs.as_deref();
+        while let Some(node) = current.filter(|node| !is_leaf(node)) {
+            right.push(&node.val);
+            current = node.rhs.as_deref().or(node.lhs.as_deref());
+        }
+        boundary.extend(right.into_iter().rev());
+
+        boundary
+    }
+
+    /// Returns the values of every level of the tree from top to bottom, each level from left to right
+    pub fn levels(&self) -> Vec<Vec<&T>> {
+        let mut levels = Vec::new();
+        let mut current = vec![self];
+        while !current.is_empty() {
+            levels.push(current.iter().map(|node| &node.val).collect());
+            current = current
+                .iter()
+                .flat_map(|node| node.lhs.iter().chain(node.rhs.iter()))
+                .map(|child| &**child)
+                .collect();
+        }
+        levels
+    }
+
+    /// Maps every value to its depth, with this node at depth 0
+    ///
+    /// If a value is contained multiple times, the smallest depth it is found at is kept
+    pub fn depth_map(&self) -> HashMap<T, usize>
+    where
+        T: Eq + Hash + Clone,
+    {
+        let mut depths = HashMap::new();
+        for (depth, level) in self.levels().into_iter().enumerate() {
+            for value in level {
+                depths.entry(value.clone()).or_insert(depth);
+            }
+        }
+        depths
+    }
+
+    /// Returns the rightmost value of every level from top to bottom, which is what is visible when looking
+    /// at the tree from the right
+    pub fn right_view(&self) -> Vec<&T> {
+        self.levels()
+            .into_iter()
+            .map(|level| *level.last().expect("levels are never empty"))
+            .collect()
+    }
+
+    /// Returns the leftmost value of every level from top to bottom, which is what is visible when looking
+    /// at the tree from the left
+    pub fn left_view(&self) -> Vec<&T> {
+        self.levels().into_iter().map(|level| level[0]).collect()
+    }
+
+    /// Formats the tree with one value per line, indented by two spaces per level of depth
+    ///
+    /// The children of a node follow it, left child first. If a node only has one child, the missing child
+    /// is printed as `-`, so that left and right children can be told apart
+    pub fn to_indented(&self) -> String
+    where
+        T: Display,
+    {
+        let mut indented = String::new();
+        let mut stack = vec![(Some(self), 0)];
+        while let Some((node, depth)) = stack.pop() {
+            indented.push_str(&"  ".repeat(depth));
+            match node {
+                Some(node) => {
+                    indented.push_str(&node.val.to_string());
+                    if node.lhs.is_some() || node.rhs.is_some() {
+                        // push the right child first so that the left one is printed first
+                        stack.push((node.rhs.as_deref(), depth + 1));
+                        stack.push((node.lhs.as_deref(), depth + 1));
+                    }
+                }
+                None => indented.push('-'),
+            }
+            indented.push('\n');
+        }
+        indented
+    }
+
+    /// Returns every path from this node to a leaf, ordered by the position of the leaf from left to right
+    pub fn root_to_leaf_paths(&self) -> Vec<Vec<&T>> {
+        fn collect<'a, T>(node: &'a Node<T>, path: &mut Vec<&'a T>, paths: &mut Vec<Vec<&'a T>>) {
+            path.push(&node.val);
+            if node.lhs.is_none() && node.rhs.is_none() {
+                paths.push(path.clone());
+            }
+            for child in node.lhs.iter().chain(node.rhs.iter()) {
+                collect(child, path, paths);
+            }
+            path.pop();
+        }
+
+        let mut paths = Vec::new();
+        collect(self, &mut Vec::new(), &mut paths);
+        paths
+    }
+
+    /// Counts the nodes in the tree whose value matches the predicate
+    pub fn count_where<F: FnMut(&T) -> bool>(&self, mut f: F) -> usize {
+        self.iter_inorder().filter(|value| f(value)).count()
+    }
+
+    /// Inserts the value into the search tree, using `cmp` to compare the values instead of [Ord]
+    ///
+    /// The same comparison has to be used for all operations on the tree, otherwise it can't be searched anymore.
+    /// If the tree already contains a value that compares equal, the value is not inserted
+    pub fn insert_by<F: FnMut(&T, &T) -> Ordering>(&mut self, value: T, mut cmp: F) {
+        let mut current = self;
+        loop {
+            let child = match cmp(&value, &current.val) {
+                Ordering::Less => &mut current.lhs,
+                Ordering::Greater => &mut current.rhs,
+                Ordering::Equal => return,
+            };
+            match child {
+                Some(next) => current = next,
+                None => {
+                    *child = Some(Box::new(Node::leaf(value)));
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Checks whether the search tree contains a value that compares equal to `value` using `cmp`
+    ///
+    /// See [Node::insert_by]
+    pub fn contains_by<F: FnMut(&T, &T) -> Ordering>(&self, value: &T, cmp: F) -> bool {
+        self.get_by(value, cmp).is_some()
+    }
+
+    /// Returns the value in the search tree that compares equal to `value` using `cmp`
+    ///
+    /// See [Node::insert_by]
+    pub fn get_by<F: FnMut(&T, &T) -> Ordering>(&self, value: &T, mut cmp: F) -> Option<&T> {
+        let mut current = self;
+        loop {
+            let child = match cmp(value, &current.val) {
+                Ordering::Less => &current.lhs,
+                Ordering::Greater => &current.rhs,
+                Ordering::Equal => return Some(&current.val),
+            };
+            current = child.as_deref()?;
+        }
+    }
+
+    /// The amount of nodes in the subtree of the node containing `value`, including the node itself, or `None`
+    /// if the value is not in the search tree
+    pub fn subtree_size(&self, value: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        let mut current = self;
+        loop {
+            let child = match value.cmp(&current.val) {
+                Ordering::Less => &current.lhs,
+                Ordering::Greater => &current.rhs,
+                Ordering::Equal => return Some(current.iter_inorder().count()),
+            };
+            current = child.as_deref()?;
+        }
+    }
+
+    /// The position of `value` in the sorted order of the tree, which is the amount of values that are
+    /// strictly less than it, or `None` if the value is not in the tree
+    ///
+    /// The tree is expected to be a valid search tree. This walks the values in in-order, so it is O(n)
+    pub fn rank(&self, value: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        for (index, current) in self.iter_inorder().enumerate() {
+            match current.cmp(value) {
+                Ordering::Less => {}
+                Ordering::Equal => return Some(index),
+                Ordering::Greater => return None,
+            }
+        }
+        None
+    }
+
+    /// The largest value in the search tree that is strictly less than `value`, or `None` if there is none
+    ///
+    /// `value` itself doesn't have to be in the tree
+    pub fn predecessor(&self, value: &T) -> Option<&T>
+    where
+        T: Ord,
+    {
+        let mut best = None;
+        let mut current = Some(self);
+        while let Some(node) = current {
+            if node.val < *value {
+                best = Some(&node.val);
+                current = node.rhs.as_deref();
+            } else {
+                current = node.lhs.as_deref();
+            }
+        }
+        best
+    }
+
+    /// The smallest value in the search tree that is strictly greater than `value`, or `None` if there is none
+    ///
+    /// `value` itself doesn't have to be in the tree
+    pub fn successor(&self, value: &T) -> Option<&T>
+    where
+        T: Ord,
+    {
+        let mut best = None;
+        let mut current = Some(self);
+        while let Some(node) = current {
+            if node.val > *value {
+                best = Some(&node.val);
+                current = node.lhs.as_deref();
+            } else {
+                current = node.rhs.as_deref();
+            }
+        }
+        best
+    }
+
+    /// The sum of all values in the tree
+    pub fn sum(&self) -> T
+    where
+        T: Copy + Sum,
+    {
+        self.iter_inorder().copied().sum()
+    }
+
+    /// The largest sum of the values along any path between two nodes in the tree. A path can start and end at
+    /// any node, but can visit every node only once
+    pub fn max_path_sum(&self) -> T
+    where
+        T: Ord + Add<Output = T> + Copy,
+    {
+        /// Returns the best path going down from the node and the best path anywhere in the subtree
+        fn best_paths<T: Ord + Add<Output = T> + Copy>(node: &Node<T>) -> (T, T) {
+            let lhs = node.lhs.as_deref().map(best_paths);
+            let rhs = node.rhs.as_deref().map(best_paths);
+            let val = node.val;
+
+            // subtrees that only make the path smaller are left out
+            let mut down = val;
+            let mut best = val;
+            for (child_down, child_best) in lhs.iter().chain(rhs.iter()) {
+                down = down.max(val + *child_down);
+                best = best.max(*child_best);
+            }
+            // the path through this node can also go down on both sides
+            if let (Some((lhs_down, _)), Some((rhs_down, _))) = (lhs, rhs) {
+                best = best.max(val + lhs_down + rhs_down);
+            }
+            best = best.max(down);
+            (down, best)
+        }
+
+        best_paths(self).1
+    }
+
+    /// Folds the tree bottom up. `f` is called for every node with its value and the already reduced results of
+    /// its left and right subtrees, or `None` if the child doesn't exist
+    pub fn reduce<B, F: FnMut(&T, Option<B>, Option<B>) -> B>(&self, mut f: F) -> B {
+        fn reduce_inner<T, B, F: FnMut(&T, Option<B>, Option<B>) -> B>(
+            node: &Node<T>,
+            f: &mut F,
+        ) -> B {
+            let lhs = node.lhs.as_ref().map(|lhs| reduce_inner(lhs, f));
+            let rhs = node.rhs.as_ref().map(|rhs| reduce_inner(rhs, f));
+            f(&node.val, lhs, rhs)
+        }
+
+        reduce_inner(self, &mut f)
+    }
+
+    /// Whether both trees have the same shape, meaning that every node has the same children in both trees.
+    /// The values are not compared
+    pub fn is_isomorphic_shape<U>(&self, other: &Node<U>) -> bool {
+        fn same_shape<T, U>(lhs: &Option<Box<Node<T>>>, rhs: &Option<Box<Node<U>>>) -> bool {
+            match (lhs, rhs) {
+                (None, None) => true,
+                (Some(lhs), Some(rhs)) => lhs.is_isomorphic_shape(rhs),
+                _ => false,
+            }
+        }
+
+        same_shape(&self.lhs, &other.lhs) && same_shape(&self.rhs, &other.rhs)
+    }
+
+    /// The amount of nodes without children
+    pub fn leaf_count(&self) -> usize {
+        self.leaf_and_internal_count().0
+    }
+
+    /// The amount of nodes with at least one child
+    pub fn internal_count(&self) -> usize {
+        self.leaf_and_internal_count().1
+    }
+
+    /// Counts the leaves and the internal nodes in a single traversal
+    fn leaf_and_internal_count(&self) -> (usize, usize) {
+        let mut counts = (0, 0);
+        let mut stack = vec![self];
+        while let Some(node) = stack.pop() {
+            if node.lhs.is_none() && node.rhs.is_none() {
+                counts.0 += 1;
+            } else {
+                counts.1 += 1;
+            }
+            stack.extend(node.lhs.iter().chain(node.rhs.iter()).map(|child| &**child));
+        }
+        counts
+    }
+
+    /// The value of a node with the largest depth. If there are multiple, the leftmost one is returned
+    pub fn deepest(&self) -> &T {
+        let mut queue = VecDeque::new();
+        queue.push_back(self);
+        let mut last = self;
+        // the levels are walked from right to left, so the last node is the leftmost one on the deepest level
+        while let Some(node) = queue.pop_front() {
+            last = node;
+            queue.extend(node.rhs.iter().chain(node.lhs.iter()).map(|child| &**child));
+        }
+        &last.val
+    }
+
+    /// The sum of the depths of all nodes, where this node has a depth of 0
+    ///
+    /// The more balanced a tree is, the smaller its internal path length
+    pub fn internal_path_length(&self) -> usize {
+        let mut sum = 0;
+        let mut stack = vec![(self, 0)];
+        while let Some((node, depth)) = stack.pop() {
+            sum += depth;
+            stack.extend(
+                node.lhs
+                    .iter()
+                    .chain(node.rhs.iter())
+                    .map(|child| (&**child, depth + 1)),
+            );
+        }
+        sum
+    }
+
+    /// The amount of nodes on the longest path from this node to a leaf, a leaf has a height of 1
+    pub fn height(&self) -> usize {
+        let lhs = self.lhs.as_ref().map(|node| node.height()).unwrap_or(0);
+        let rhs = self.rhs.as_ref().map(|node| node.height()).unwrap_or(0);
+        lhs.max(rhs) + 1
+    }
+
+    /// Whether the heights of the two subtrees of every node differ by at most one
+    pub fn is_balanced(&self) -> bool {
+        self.first_unbalanced().is_none()
+    }
+
+    /// The value of the lowest node where the heights of the two subtrees differ by more than one, which is the
+    /// node a rotation would have to fix, or `None` if the tree is balanced
+    ///
+    /// See [Node::is_balanced]
+    pub fn first_unbalanced(&self) -> Option<&T> {
+        /// Returns the height of the subtree or the value of the unbalanced node
+        fn balanced_height<T>(node: Option<&Node<T>>) -> Result<usize, &T> {
+            let node = match node {
+                None => return Ok(0),
+                Some(node) => node,
+            };
+            let lhs = balanced_height(node.lhs.as_deref())?;
+            let rhs = balanced_height(node.rhs.as_deref())?;
+            if lhs.max(rhs) - lhs.min(rhs) > 1 {
+                Err(&node.val)
+            } else {
+                Ok(lhs.max(rhs) + 1)
+            }
+        }
+
+        balanced_height(Some(self)).err()
+    }
+
+    /// Returns a tree with the same shape, where every node contains its balance factor, which is the height of
+    /// its left subtree minus the height of its right subtree
+    ///
+    /// See [Node::is_balanced]
+    pub fn balance_factors(&self) -> Node<isize> {
+        /// Returns the tree of the balance factors and the height of the subtree
+        fn factors<T>(node: &Node<T>) -> (Node<isize>, usize) {
+            let (lhs, lhs_height) = node.lhs.as_deref().map_or((None, 0), |lhs| {
+                let (lhs, height) = factors(lhs);
+                (Some(lhs), height)
+            });
+            let (rhs, rhs_height) = node.rhs.as_deref().map_or((None, 0), |rhs| {
+                let (rhs, height) = factors(rhs);
+                (Some(rhs), height)
+            });
+            let factor = lhs_height as isize - rhs_height as isize;
+            (Node::new(factor, lhs, rhs), lhs_height.max(rhs_height) + 1)
+        }
+
+        factors(self).0
+    }
+
+    /// Whether `sub` is equal to the subtree of one of the nodes in this tree, comparing both the shape and
+    /// the values
+    pub fn contains_subtree(&self, sub: &Node<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        let mut stack = vec![self];
+        while let Some(node) = stack.pop() {
+            if node == sub {
+                return true;
+            }
+            stack.extend(node.lhs.iter().chain(node.rhs.iter()).map(|child| &**child));
+        }
+        false
+    }
+
+    /// Whether the tree is complete, meaning that every level is full, except for maybe the last one, which
+    /// is filled from left to right. This is the shape that a binary heap stored in an array has
+    pub fn is_complete(&self) -> bool {
+        let mut queue = VecDeque::new();
+        queue.push_back(self);
+        // once a child is missing, no other node may have children anymore
+        let mut seen_gap = false;
+        while let Some(node) = queue.pop_front() {
+            for child in [&node.lhs, &node.rhs].iter() {
+                match child {
+                    Some(_) if seen_gap => return false,
+                    Some(child) => queue.push_back(&**child),
+                    None => seen_gap = true,
+                }
+            }
+        }
+        true
+    }
+
+    /// Whether the tree is a valid binary search tree, meaning that the in-order values are strictly increasing
+    pub fn is_search_tree(&self) -> bool
+    where
+        T: Ord,
+    {
+        let mut values = self.iter_inorder();
+        let mut prev = match values.next() {
+            Some(value) => value,
+            None => return true,
+        };
+        for value in values {
+            if prev >= value {
+                return false;
+            }
+            prev = value;
+        }
+        true
+    }
+}
+
+impl<T> BinaryTree<T> {
+    /// Creates an empty tree
+    pub fn new() -> Self {
+        Self(None)
+    }
+
+    /// The root node of the tree, or `None` if the tree is empty
+    pub fn root(&self) -> Option<&Node<T>> {
+        self.0.as_ref()
+    }
+
+    /// Builds a height-balanced binary search tree from sorted values
+    ///
+    /// The values are expected to be sorted and unique, otherwise the result is not a valid search tree
+    pub fn from_sorted(values: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        fn build<T: Clone>(values: &[T]) -> Option<Node<T>> {
+            if values.is_empty() {
+                return None;
+            }
+            let mid = values.len() / 2;
+            Some(Node::new(
+                values[mid].clone(),
+                build(&values[..mid]),
+                build(&values[mid + 1..]),
+            ))
+        }
+
+        Self(build(values))
+    }
+
+    /// Builds a height-balanced binary search tree from a sorted linked list in O(n)
+    ///
+    /// The tree is built in in-order while walking the list from the front, so no random access is needed.
+    /// The values are expected to be sorted and unique, otherwise the result is not a valid search tree
+    pub fn from_sorted_linked_list(list: LinkedList<T>) -> Self {
+        fn build<T, I: Iterator<Item = T>>(len: usize, values: &mut I) -> Option<Node<T>> {
+            if len == 0 {
+                return None;
+            }
+            let lhs = build(len / 2, values);
+            let val = values.next().expect("the list is as long as its length");
+            let rhs = build(len - len / 2 - 1, values);
+            Some(Node::new(val, lhs, rhs))
+        }
+
+        let len = list.len();
+        Self(build(len, &mut list.into_iter()))
+    }
+
+    /// Builds a height-balanced binary search tree from values in any order
+    ///
+    /// Duplicate values are removed, the tree only contains each value once
+    pub fn from_unsorted<I: IntoIterator<Item = T>>(iter: I) -> Self
+    where
+        T: Ord + Clone,
+    {
+        let mut values = iter.into_iter().collect::<Vec<_>>();
+        values.sort();
+        values.dedup();
+        Self::from_sorted(&values)
+    }
+
+    /// Moves the node containing `value` to the root using rotations, like a splay tree does when accessing a
+    /// value. Returns whether the value was found, if it wasn't, the tree is not changed
+    ///
+    /// The in-order of the values is kept, but the tree may become unbalanced
+    pub fn splay(&mut self, value: &T) -> bool
+    where
+        T: Ord,
+    {
+        /// Moves the value to the root of the subtree, the value has to be in the subtree
+        fn splay<T: Ord>(node: &mut Node<T>, value: &T) {
+            match value.cmp(&node.val) {
+                Ordering::Equal => {}
+                Ordering::Less => {
+                    let lhs = node.lhs.as_deref_mut().expect("the value is in the tree");
+                    match value.cmp(&lhs.val) {
+                        Ordering::Equal => {}
+                        // zig-zig: rotate the grandparent first, then the parent
+                        Ordering::Less => {
+                            splay(lhs.lhs.as_deref_mut().unwrap(), value);
+                            node.rotate_right();
+                        }
+                        // zig-zag: rotate the parent towards the grandparent first
+                        Ordering::Greater => {
+                            splay(lhs.rhs.as_deref_mut().unwrap(), value);
+                            lhs.rotate_left();
+                        }
+                    }
+                    node.rotate_right();
+                }
+                Ordering::Greater => {
+                    let rhs = node.rhs.as_deref_mut().expect("the value is in the tree");
+                    match value.cmp(&rhs.val) {
+                        Ordering::Equal => {}
+                        Ordering::Greater => {
+                            splay(rhs.rhs.as_deref_mut().unwrap(), value);
+                            node.rotate_left();
+                        }
+                        Ordering::Less => {
+                            splay(rhs.lhs.as_deref_mut().unwrap(), value);
+                            rhs.rotate_right();
+                        }
+                    }
+                    node.rotate_left();
+                }
+            }
+        }
+
+        match &mut self.0 {
+            Some(root) if root.contains_by(value, T::cmp) => {
+                splay(root, value);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Merges two binary search trees into a new height-balanced binary search tree
+    ///
+    /// Values that are contained in both trees are only contained once in the merged tree
+    pub fn merge(self, other: BinaryTree<T>) -> BinaryTree<T>
+    where
+        T: Ord + Clone,
+    {
+        let mut lhs = self
+            .root()
+            .into_iter()
+            .flat_map(Node::iter_inorder)
+            .peekable();
+        let mut rhs = other
+            .root()
+            .into_iter()
+            .flat_map(Node::iter_inorder)
+            .peekable();
+
+        let mut values = Vec::new();
+        loop {
+            let next = match (lhs.peek(), rhs.peek()) {
+                (Some(l), Some(r)) if l <= r => lhs.next(),
+                (Some(_), Some(_)) => rhs.next(),
+                (Some(_), None) => lhs.next(),
+                (None, _) => rhs.next(),
+            };
+            match next {
+                Some(value) if values.last() != Some(value) => values.push(value.clone()),
+                Some(_) => {}
+                None => break,
+            }
+        }
+
+        Self::from_sorted(&values)
+    }
+
+    /// Moves the values into a list in in-order (left subtree, node, right subtree)
+    pub fn into_linked_list_inorder(self) -> LinkedList<T> {
+        fn push<T>(node: Node<T>, list: &mut LinkedList<T>) {
+            if let Some(lhs) = node.lhs {
+                push(*lhs, list);
+            }
+            list.push_back(node.val);
+            if let Some(rhs) = node.rhs {
+                push(*rhs, list);
+            }
+        }
+
+        let mut list = LinkedList::new();
+        if let Some(root) = self.0 {
+            push(root, &mut list);
+        }
+        list
+    }
+
+    /// Moves the values into a list in pre-order (node, left subtree, right subtree)
+    pub fn into_linked_list_preorder(self) -> LinkedList<T> {
+        fn push<T>(node: Node<T>, list: &mut LinkedList<T>) {
+            list.push_back(node.val);
+            if let Some(lhs) = node.lhs {
+                push(*lhs, list);
+            }
+            if let Some(rhs) = node.rhs {
+                push(*rhs, list);
+            }
+        }
+
+        let mut list = LinkedList::new();
+        if let Some(root) = self.0 {
+            push(root, &mut list);
+        }
+        list
+    }
+
+    /// Moves the values into a list in post-order (left subtree, right subtree, node)
+    pub fn into_linked_list_postorder(self) -> LinkedList<T> {
+        fn push<T>(node: Node<T>, list: &mut LinkedList<T>) {
+            if let Some(lhs) = node.lhs {
+                push(*lhs, list);
+            }
+            if let Some(rhs) = node.rhs {
+                push(*rhs, list);
+            }
+            list.push_back(node.val);
+        }
+
+        let mut list = LinkedList::new();
+        if let Some(root) = self.0 {
+            push(root, &mut list);
+        }
+        list
+    }
+
+    /// Moves the values into a list in level-order, every level from left to right
+    pub fn into_linked_list_level_order(self) -> LinkedList<T> {
+        let mut list = LinkedList::new();
+        let mut queue = self.0.into_iter().collect::<VecDeque<_>>();
+        while let Some(node) = queue.pop_front() {
+            queue.extend(node.lhs.map(|lhs| *lhs));
+            queue.extend(node.rhs.map(|rhs| *rhs));
+            list.push_back(node.val);
+        }
+        list
+    }
+}
+
+impl<T> Default for BinaryTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An iterator over the values of the tree in in-order, see [Node::iter_inorder]
+pub struct InOrder<'a, T> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> InOrder<'a, T> {
+    fn push_left(&mut self, mut node: &'a Node<T>) {
+        self.stack.push(node);
+        while let Some(lhs) = &node.lhs {
+            node = lhs;
+            self.stack.push(node);
+        }
+    }
+}
+
+impl<'a, T> Iterator for InOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        if let Some(rhs) = &node.rhs {
+            self.push_left(rhs);
+        }
+        Some(&node.val)
+    }
+}
+
+/// An iterator over mutable references to the values of the tree in in-order, see [Node::iter_inorder_mut]
+pub struct InOrderMut<'a, T> {
+    // raw pointers are used because the references to the values handed out would otherwise borrow the
+    // whole node, including the right subtree that still has to be walked
+    stack: Vec<*mut Node<T>>,
+    _marker: PhantomData<&'a mut Node<T>>,
+}
+
+impl<'a, T> InOrderMut<'a, T> {
+    fn push_left(&mut self, mut node: *mut Node<T>) {
+        self.stack.push(node);
+        // SAFETY: all nodes on the stack are valid for 'a, and only the child fields are accessed here,
+        // which are never handed out
+        while let Some(lhs) = unsafe { &mut (*node).lhs } {
+            node = &mut **lhs;
+            self.stack.push(node);
+        }
+    }
+}
+
+impl<'a, T> Iterator for InOrderMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        // SAFETY: the node is valid for 'a, and every node is popped only once, so the references to the
+        // values are disjoint
+        unsafe {
+            if let Some(rhs) = &mut (*node).rhs {
+                self.push_left(&mut **rhs);
+            }
+            Some(&mut (*node).val)
+        }
+    }
+}
+
+/// An iterator over the values of the tree in in-order using a Morris traversal, see [Node::iter_inorder_morris]
+pub struct InOrderMorris<'a, T> {
+    // the links are only accessed through raw pointers, since a threaded link aliases the box of an ancestor.
+    // the subtrees are detached from the root while iterating, so leaking the iterator can't leave a
+    // threaded tree behind
+    root: *mut Node<T>,
+    lhs: *mut Node<T>,
+    rhs: *mut Node<T>,
+    current: *mut Node<T>,
+    state: MorrisState,
+    _marker: PhantomData<&'a mut Node<T>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MorrisState {
+    Left,
+    Right,
+    Done,
+}
+
+/// Returns a pointer to the `lhs` link of the node as a raw pointer
+/// # Safety
+/// The node must be valid
+unsafe fn lhs_link<T>(node: *mut Node<T>) -> *mut *mut Node<T> {
+    // `Option<Box<Node<T>>>` is guaranteed to have the same layout as `*mut Node<T>`, with `None` being null
+    ptr::addr_of_mut!((*node).lhs).cast()
+}
+
+/// Returns a pointer to the `rhs` link of the node as a raw pointer
+/// # Safety
+/// The node must be valid
+unsafe fn rhs_link<T>(node: *mut Node<T>) -> *mut *mut Node<T> {
+    ptr::addr_of_mut!((*node).rhs).cast()
+}
+
+impl<'a, T> InOrderMorris<'a, T> {
+    fn new(root: &'a mut Node<T>) -> Self {
+        let root: *mut Node<T> = root;
+        // SAFETY: the root is valid, the links are moved out as raw pointers and written back on drop
+        let (lhs, rhs) = unsafe {
+            (
+                lhs_link(root).replace(ptr::null_mut()),
+                rhs_link(root).replace(ptr::null_mut()),
+            )
+        };
+        Self {
+            root,
+            lhs,
+            rhs,
+            current: lhs,
+            state: MorrisState::Left,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Advances the Morris traversal of the current subtree and returns the next node
+    /// # Safety
+    /// `current` must be null or a node of the subtree that is currently traversed
+    unsafe fn step(&mut self) -> Option<*mut Node<T>> {
+        while !self.current.is_null() {
+            let lhs = *lhs_link(self.current);
+            if lhs.is_null() {
+                let node = self.current;
+                self.current = *rhs_link(node);
+                return Some(node);
+            }
+
+            // find the in-order predecessor, which either has no right child or is already threaded back
+            let mut pred = lhs;
+            while !(*rhs_link(pred)).is_null() && *rhs_link(pred) != self.current {
+                pred = *rhs_link(pred);
+            }
+
+            if (*rhs_link(pred)).is_null() {
+                // thread the predecessor back to the current node and walk the left subtree first
+                *rhs_link(pred) = self.current;
+                self.current = lhs;
+            } else {
+                // the left subtree has been walked, remove the thread again
+                *rhs_link(pred) = ptr::null_mut();
+                let node = self.current;
+                self.current = *rhs_link(node);
+                return Some(node);
+            }
+        }
+        None
+    }
+}
+
+impl<'a, T> Iterator for InOrderMorris<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: all nodes are valid for 'a, and only the links are written, never the values
+        unsafe {
+            match self.state {
+                MorrisState::Left => match self.step() {
+                    Some(node) => Some(&(*node).val),
+                    None => {
+                        self.state = MorrisState::Right;
+                        self.current = self.rhs;
+                        Some(&(*self.root).val)
+                    }
+                },
+                MorrisState::Right => match self.step() {
+                    Some(node) => Some(&(*node).val),
+                    None => {
+                        self.state = MorrisState::Done;
+                        None
+                    }
+                },
+                MorrisState::Done => None,
+            }
+        }
+    }
+}
+
+impl<'a, T> Drop for InOrderMorris<'a, T> {
+    fn drop(&mut self) {
+        // finishing the traversal removes all threads that are left
+        while self.next().is_some() {}
+        // SAFETY: the root is valid and the subtrees are not threaded anymore
+        unsafe {
+            *lhs_link(self.root) = self.lhs;
+            *rhs_link(self.root) = self.rhs;
+        }
+    }
+}
+
+/// The side of a child node
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// The left child, `lhs`
+    Left,
+    /// The right child, `rhs`
+    Right,
+}
+
+/// An event emitted by [Node::walk]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeEvent<T> {
+    /// The walk descends into the node
+    Enter(T),
+    /// The subtree of the node has been walked completely
+    Leave(T),
+}
+
+/// A depth first walk over the tree, see [Node::walk]
+pub struct Walk<'a, T> {
+    stack: Vec<(&'a Node<T>, bool)>,
+}
+
+impl<'a, T> Iterator for Walk<'a, T> {
+    type Item = TreeEvent<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, entered) = self.stack.pop()?;
+        if entered {
+            return Some(TreeEvent::Leave(&node.val));
+        }
+        self.stack.push((node, true));
+        // push the right child first so that the left one is walked first
+        for child in node.rhs.iter().chain(node.lhs.iter()) {
+            self.stack.push((child, false));
+        }
+        Some(TreeEvent::Enter(&node.val))
+    }
 }
 
 pub trait DisplayTree {
@@ -29,6 +1160,11 @@ pub trait DisplayTree {
     fn offset_x(&self) -> usize;
     fn amount_of_con(&self) -> usize;
     fn display(&self) -> String;
+    /// Displays the tree with every value right-aligned to at least `width` characters
+    ///
+    /// Values that are wider than `width` are not truncated, which breaks the alignment of the columns.
+    /// A `width` of 0 displays the tree unpadded, the same as [DisplayTree::display]
+    fn display_padded(&self, width: usize) -> String;
 }
 
 impl<T: Display + Debug> DisplayTree for Node<T> {
@@ -64,85 +1200,109 @@ impl<T: Display + Debug> DisplayTree for Node<T> {
     }
 
     fn display(&self) -> String {
-        const SPACE: &str = " ";
+        display_tree(self, None)
+    }
 
-        let mut str = String::new();
+    fn display_padded(&self, width: usize) -> String {
+        match width {
+            0 => display_tree(self, None),
+            width => display_tree(self, Some(width)),
+        }
+    }
+}
 
-        let mut current_nodes = vec![self];
+/// Displays the tree. If a `cell_width` is given, every value and connection takes up a cell of that width,
+/// otherwise every value takes up as many characters as its string representation.
+fn display_tree<T: Display + Debug>(root: &Node<T>, cell_width: Option<usize>) -> String {
+    const SPACE: &str = " ";
 
-        while !current_nodes.is_empty() {
-            // display node layer
+    let unit = cell_width.unwrap_or(1);
+    let value_str = |node: &Node<T>| match cell_width {
+        None => node.val.to_string(),
+        Some(width) => format!("{:>width$}", node.val, width = width),
+    };
+    // the amount of layout units the value takes up
+    let value_len = |node: &Node<T>| match cell_width {
+        None => node.val.to_string().len(),
+        Some(_) => 1,
+    };
 
-            let mut offset = 0;
-            let mut is_left = true;
-            let nodes_with_offset = current_nodes
-                .iter()
-                .map(|node| {
-                    offset += node.offset_x();
-                    let this_offset = offset;
-                    offset += node.val.to_string().len();
-                    offset += node.offset_x() + 1;
-                    if node.depth() == 0 && is_left {
-                        offset += 2;
-                    }
-                    is_left = !is_left;
-                    (this_offset, node)
-                })
-                .collect::<Vec<_>>();
+    let mut str = String::new();
 
-            let mut prev_offset = 0;
-            for (offset, node) in &nodes_with_offset {
-                let diff_offset = offset - prev_offset;
-                str.push_str(&SPACE.repeat(diff_offset));
-                let value_str = node.val.to_string();
-                str.push_str(&value_str);
-                prev_offset += diff_offset + value_str.len();
-            }
-            str.push('\n');
-            // print node connections
+    let mut current_nodes = vec![root];
 
-            let amount_of_con = current_nodes
-                .first()
-                .map(|node| node.amount_of_con())
-                .unwrap_or(0);
+    while !current_nodes.is_empty() {
+        // display node layer
 
-            for i in 0..amount_of_con {
-                let mut connections = nodes_with_offset
-                    .iter()
-                    .map(|(offset, _)| (offset - 1 - i, '/'))
-                    .chain(
-                        nodes_with_offset
-                            .iter()
-                            .map(|(offset, _)| (offset + 1 + i, '\\')),
-                    )
-                    .collect::<Vec<_>>();
-                connections.sort_by(|(a_offset, _), (b_offset, _)| a_offset.cmp(b_offset));
-
-                let mut prev_offset = 0;
-                for (offset, con) in connections {
-                    let diff_offset = offset - prev_offset;
-                    str.push_str(&SPACE.repeat(diff_offset));
-                    str.push(con);
-                    prev_offset += diff_offset + 1;
+        let mut offset = 0;
+        let mut is_left = true;
+        let nodes_with_offset = current_nodes
+            .iter()
+            .map(|node| {
+                offset += node.offset_x();
+                let this_offset = offset;
+                offset += value_len(node);
+                offset += node.offset_x() + 1;
+                if node.depth() == 0 && is_left {
+                    offset += 2;
                 }
-                str.push('\n');
-            }
+                is_left = !is_left;
+                (this_offset, node)
+            })
+            .collect::<Vec<_>>();
+
+        let mut prev_offset = 0;
+        for (offset, node) in &nodes_with_offset {
+            let diff_offset = offset - prev_offset;
+            str.push_str(&SPACE.repeat(diff_offset * unit));
+            str.push_str(&value_str(node));
+            prev_offset += diff_offset + value_len(node);
+        }
+        str.push('\n');
+        // print node connections
+
+        let amount_of_con = current_nodes
+            .first()
+            .map(|node| node.amount_of_con())
+            .unwrap_or(0);
 
-            current_nodes = current_nodes
+        for i in 0..amount_of_con {
+            let mut connections = nodes_with_offset
                 .iter()
-                .map(|node| [&node.lhs, &node.rhs])
-                .flatten()
-                .flatten()
-                .map(|boxed| &**boxed)
+                .map(|(offset, _)| (offset - 1 - i, '/'))
+                .chain(
+                    nodes_with_offset
+                        .iter()
+                        .map(|(offset, _)| (offset + 1 + i, '\\')),
+                )
                 .collect::<Vec<_>>();
+            connections.sort_by_key(|(offset, _)| *offset);
+
+            let mut prev_offset = 0;
+            for (offset, con) in connections {
+                let diff_offset = offset - prev_offset;
+                str.push_str(&SPACE.repeat(diff_offset * unit));
+                str.push_str(&format!("{:>unit$}", con, unit = unit));
+                prev_offset += diff_offset + 1;
+            }
+            str.push('\n');
         }
 
-        str
+        current_nodes = current_nodes
+            .iter()
+            .flat_map(|node| [&node.lhs, &node.rhs])
+            .flatten()
+            .map(|boxed| &**boxed)
+            .collect::<Vec<_>>();
     }
+
+    str
 }
 
+#[cfg(test)]
 mod test {
-    use crate::binary_tree::{DisplayTree, Node};
+    use crate::binary_tree::{BinaryTree, DisplayTree, Node, Side, TreeEvent};
+    use crate::linked_list::LinkedList;
 
     #[test]
     fn print_cool_tree() {
@@ -166,4 +1326,736 @@ mod test {
 
         // panic!("let this fail for printing");
     }
+
+    #[test]
+    fn walk() {
+        let tree = Node::new(2, Some(Node::leaf(1)), Some(Node::leaf(3)));
+        let events = tree.walk().collect::<Vec<_>>();
+        assert_eq!(
+            events,
+            vec![
+                TreeEvent::Enter(&2),
+                TreeEvent::Enter(&1),
+                TreeEvent::Leave(&1),
+                TreeEvent::Enter(&3),
+                TreeEvent::Leave(&3),
+                TreeEvent::Leave(&2),
+            ]
+        );
+    }
+
+    #[test]
+    fn from_unsorted() {
+        let tree = BinaryTree::from_unsorted(vec![5, 1, 3, 2, 4, 3]);
+        let root = tree.root().unwrap();
+        assert!(root.is_search_tree());
+        assert!(root.is_balanced());
+        assert_eq!(root.height(), 3);
+        assert_eq!(
+            root.iter_inorder().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5]
+        );
+
+        assert_eq!(BinaryTree::<i32>::from_unsorted(vec![]).root(), None);
+    }
+
+    #[test]
+    fn from_sorted_linked_list() {
+        let tree = BinaryTree::from_sorted_linked_list((1..=7).collect::<LinkedList<_>>());
+        let root = tree.root().unwrap();
+        assert!(root.is_search_tree());
+        assert!(root.is_balanced());
+        assert_eq!(root.height(), 3);
+        assert_eq!(
+            root.iter_inorder().copied().collect::<Vec<_>>(),
+            (1..=7).collect::<Vec<_>>()
+        );
+
+        let tree = BinaryTree::from_sorted_linked_list((1..=10).collect::<LinkedList<_>>());
+        assert!(tree.root().unwrap().is_balanced());
+        assert_eq!(
+            BinaryTree::<i32>::from_sorted_linked_list(LinkedList::new()).root(),
+            None
+        );
+    }
+
+    #[test]
+    fn count_where() {
+        let tree = BinaryTree::from_sorted(&[1, 2, 3, 4, 5, 6, 7]);
+        let root = tree.root().unwrap();
+        assert_eq!(root.count_where(|value| value % 2 == 0), 3);
+        assert_eq!(root.count_where(|_| true), 7);
+    }
+
+    #[test]
+    fn from_shape() {
+        let tree = Node::from_shape(&[
+            (1, 0, Side::Left),
+            (2, 0, Side::Right),
+            (3, 1, Side::Right),
+            (4, 2, Side::Right),
+        ]);
+        assert_eq!(
+            tree,
+            Node::new(
+                1,
+                None,
+                Some(Node::new(
+                    2,
+                    None,
+                    Some(Node::new(3, None, Some(Node::leaf(4))))
+                ))
+            )
+        );
+        let preorder = tree
+            .walk()
+            .filter_map(|event| match event {
+                TreeEvent::Enter(value) => Some(*value),
+                TreeEvent::Leave(_) => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(preorder, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn rotate_values_right() {
+        let mut tree = BinaryTree::from_sorted(&[1, 2, 3, 4])
+            .root()
+            .unwrap()
+            .clone();
+        let shape = tree.clone();
+        tree.rotate_values_right(1);
+        assert_eq!(
+            tree.iter_inorder().copied().collect::<Vec<_>>(),
+            vec![4, 1, 2, 3]
+        );
+        assert!(tree.is_isomorphic_shape(&shape));
+
+        tree.rotate_values_right(6);
+        assert_eq!(
+            tree.iter_inorder().copied().collect::<Vec<_>>(),
+            vec![2, 3, 4, 1]
+        );
+
+        let mut leaf = Node::leaf(1);
+        leaf.rotate_values_right(3);
+        assert_eq!(leaf, Node::leaf(1));
+    }
+
+    #[test]
+    fn iter_inorder_mut() {
+        let mut tree = Node::from_shape(&[
+            (4, 0, Side::Left),
+            (2, 0, Side::Left),
+            (6, 0, Side::Right),
+            (1, 1, Side::Left),
+            (3, 1, Side::Right),
+            (5, 2, Side::Left),
+        ]);
+        let refs = tree.iter_inorder_mut().collect::<Vec<_>>();
+        for value in refs {
+            *value *= 2;
+        }
+        assert_eq!(
+            tree.iter_inorder().copied().collect::<Vec<_>>(),
+            vec![2, 4, 6, 8, 10, 12]
+        );
+        assert!(tree.is_search_tree());
+    }
+
+    #[test]
+    fn iter_inorder_morris() {
+        let mut tree = Node::from_shape(&[
+            (4, 0, Side::Left),
+            (2, 0, Side::Left),
+            (6, 0, Side::Right),
+            (1, 1, Side::Left),
+            (3, 1, Side::Right),
+            (5, 2, Side::Left),
+            (7, 2, Side::Right),
+        ]);
+        let original = tree.clone();
+        assert_eq!(
+            tree.iter_inorder_morris().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5, 6, 7]
+        );
+        assert_eq!(tree, original);
+
+        // stop right after the first value, while its right link is still threaded back to its parent
+        let mut iter = tree.iter_inorder_morris();
+        assert_eq!(iter.next(), Some(&1));
+        drop(iter);
+        assert_eq!(tree, original);
+
+        let mut iter = tree.iter_inorder_morris();
+        assert_eq!(iter.nth(4), Some(&5));
+        drop(iter);
+        assert_eq!(tree, original);
+
+        let mut leaf = Node::leaf(1);
+        assert_eq!(leaf.iter_inorder_morris().collect::<Vec<_>>(), vec![&1]);
+        assert_eq!(leaf, Node::leaf(1));
+    }
+
+    #[test]
+    fn insert_by() {
+        let by_len = |a: &&str, b: &&str| a.len().cmp(&b.len());
+        let mut tree = Node::leaf("four");
+        for value in ["a", "seven!!", "to", "three", "sixsix", "one"].iter() {
+            tree.insert_by(value, by_len);
+        }
+        assert_eq!(
+            tree.iter_inorder().copied().collect::<Vec<_>>(),
+            vec!["a", "to", "one", "four", "three", "sixsix", "seven!!"]
+        );
+        assert!(tree.contains_by(&"three", by_len));
+        assert!(!tree.contains_by(&"eight!!!", by_len));
+        // values with the same length are equal for this comparison
+        assert_eq!(tree.get_by(&"xyz", by_len), Some(&"one"));
+        tree.insert_by("two", by_len);
+        assert_eq!(tree.count_where(|_| true), 7);
+    }
+
+    #[test]
+    fn rotate() {
+        let tree = Node::new(
+            4,
+            Some(Node::new(2, Some(Node::leaf(1)), Some(Node::leaf(3)))),
+            Some(Node::leaf(5)),
+        );
+        let mut rotated = tree.clone();
+        rotated.rotate_right();
+        assert_eq!(
+            rotated,
+            Node::new(
+                2,
+                Some(Node::leaf(1)),
+                Some(Node::new(4, Some(Node::leaf(3)), Some(Node::leaf(5)))),
+            )
+        );
+        rotated.rotate_left();
+        assert_eq!(rotated, tree);
+
+        let mut leaf = Node::leaf(1);
+        leaf.rotate_left();
+        leaf.rotate_right();
+        assert_eq!(leaf, Node::leaf(1));
+    }
+
+    #[test]
+    fn splay() {
+        // a chain to the right, so the largest value is the deepest one
+        let mut root = Node::leaf(1);
+        for value in 2..=7 {
+            root.insert_by(value, i32::cmp);
+        }
+        let mut tree = BinaryTree(Some(root));
+        let inorder = (1..=7).collect::<Vec<_>>();
+
+        assert!(tree.splay(&7));
+        let root = tree.root().unwrap();
+        assert_eq!(root.val, 7);
+        assert!(root.is_search_tree());
+        assert_eq!(root.iter_inorder().copied().collect::<Vec<_>>(), inorder);
+        assert!(root.height() < 7);
+
+        for value in [4, 1, 6, 3].iter() {
+            assert!(tree.splay(value));
+            let root = tree.root().unwrap();
+            assert_eq!(root.val, *value);
+            assert_eq!(root.iter_inorder().copied().collect::<Vec<_>>(), inorder);
+        }
+
+        let unchanged = tree.clone();
+        assert!(!tree.splay(&10));
+        assert_eq!(tree, unchanged);
+        assert!(!BinaryTree::new().splay(&1));
+    }
+
+    #[test]
+    fn subtree_size() {
+        let tree = BinaryTree::from_sorted(&[1, 2, 3, 4, 5, 6, 7]);
+        let root = tree.root().unwrap();
+        assert_eq!(root.subtree_size(&4), Some(7));
+        assert_eq!(root.subtree_size(&2), Some(3));
+        assert_eq!(root.subtree_size(&6), Some(3));
+        assert_eq!(root.subtree_size(&5), Some(1));
+        assert_eq!(root.subtree_size(&8), None);
+    }
+
+    #[test]
+    fn rank() {
+        let tree = BinaryTree::from_sorted(&[10, 20, 30, 40]);
+        let root = tree.root().unwrap();
+        assert_eq!(root.rank(&30), Some(2));
+        assert_eq!(root.rank(&10), Some(0));
+        assert_eq!(root.rank(&40), Some(3));
+        assert_eq!(root.rank(&25), None);
+        assert_eq!(root.rank(&50), None);
+    }
+
+    #[test]
+    fn predecessor_successor() {
+        let tree = BinaryTree::from_sorted(&[10, 20, 30, 40]);
+        let root = tree.root().unwrap();
+        assert_eq!(root.predecessor(&30), Some(&20));
+        assert_eq!(root.predecessor(&10), None);
+        assert_eq!(root.predecessor(&25), Some(&20));
+        assert_eq!(root.predecessor(&100), Some(&40));
+        assert_eq!(root.successor(&30), Some(&40));
+        assert_eq!(root.successor(&40), None);
+        assert_eq!(root.successor(&0), Some(&10));
+    }
+
+    #[test]
+    fn truncate_depth() {
+        let full = Node::new(
+            4,
+            Some(Node::new(2, Some(Node::leaf(1)), Some(Node::leaf(3)))),
+            Some(Node::new(6, Some(Node::leaf(5)), Some(Node::leaf(7)))),
+        );
+
+        let mut tree = full.clone();
+        tree.truncate_depth(1);
+        assert_eq!(tree, Node::new(4, Some(Node::leaf(2)), Some(Node::leaf(6))));
+        assert_eq!(tree.leaves().collect::<Vec<_>>(), vec![&2, &6]);
+        assert_eq!(tree.iter_inorder().count(), 3);
+
+        let mut tree = full.clone();
+        tree.truncate_depth(0);
+        assert_eq!(tree, Node::leaf(4));
+
+        let mut tree = full.clone();
+        tree.truncate_depth(5);
+        assert_eq!(tree, full);
+    }
+
+    #[test]
+    fn leaf_and_internal_count() {
+        let tree = Node::new(
+            4,
+            Some(Node::new(2, Some(Node::leaf(1)), Some(Node::leaf(3)))),
+            Some(Node::new(6, Some(Node::leaf(5)), None)),
+        );
+        assert_eq!(tree.leaf_count(), 3);
+        assert_eq!(tree.internal_count(), 3);
+
+        assert_eq!(Node::leaf(1).leaf_count(), 1);
+        assert_eq!(Node::leaf(1).internal_count(), 0);
+    }
+
+    #[test]
+    fn deepest() {
+        let tree = Node::from_shape(&[
+            (1, 0, Side::Left),
+            (2, 0, Side::Left),
+            (3, 0, Side::Right),
+            (4, 2, Side::Left),
+            (5, 3, Side::Right),
+        ]);
+        assert_eq!(tree.deepest(), &5);
+
+        let tree = Node::new(
+            4,
+            Some(Node::new(2, Some(Node::leaf(1)), Some(Node::leaf(3)))),
+            Some(Node::new(6, Some(Node::leaf(5)), Some(Node::leaf(7)))),
+        );
+        assert_eq!(tree.deepest(), &1);
+
+        assert_eq!(Node::leaf(1).deepest(), &1);
+    }
+
+    #[test]
+    fn depth_map() {
+        let tree = Node::new(
+            4,
+            Some(Node::new(2, Some(Node::leaf(1)), Some(Node::leaf(3)))),
+            Some(Node::leaf(6)),
+        );
+        let depths = tree.depth_map();
+        assert_eq!(depths.len(), 5);
+        assert_eq!(depths[&4], 0);
+        assert_eq!(depths[&2], 1);
+        assert_eq!(depths[&6], 1);
+        assert_eq!(depths[&1], 2);
+        assert_eq!(depths[&3], 2);
+
+        let duplicates = Node::new(1, Some(Node::new(2, Some(Node::leaf(1)), None)), None);
+        assert_eq!(duplicates.depth_map()[&1], 0);
+    }
+
+    #[test]
+    fn right_and_left_view() {
+        let tree = Node::new(
+            1,
+            Some(Node::new(
+                2,
+                None,
+                Some(Node::new(5, Some(Node::leaf(7)), None)),
+            )),
+            Some(Node::new(3, None, Some(Node::leaf(4)))),
+        );
+        assert_eq!(tree.right_view(), vec![&1, &3, &4, &7]);
+        assert_eq!(tree.left_view(), vec![&1, &2, &5, &7]);
+
+        assert_eq!(Node::leaf(1).right_view(), vec![&1]);
+        assert_eq!(Node::leaf(1).left_view(), vec![&1]);
+    }
+
+    #[test]
+    fn to_indented() {
+        let tree = Node::new(
+            4,
+            Some(Node::new(2, Some(Node::leaf(1)), Some(Node::leaf(3)))),
+            Some(Node::leaf(6)),
+        );
+        assert_eq!(tree.to_indented(), "4\n  2\n    1\n    3\n  6\n");
+
+        let tree = Node::new(4, None, Some(Node::new(6, Some(Node::leaf(5)), None)));
+        assert_eq!(tree.to_indented(), "4\n  -\n  6\n    5\n    -\n");
+    }
+
+    #[test]
+    fn boundary() {
+        let tree = Node::new(
+            20,
+            Some(Node::new(
+                8,
+                Some(Node::leaf(4)),
+                Some(Node::new(12, Some(Node::leaf(10)), Some(Node::leaf(14)))),
+            )),
+            Some(Node::new(22, None, Some(Node::leaf(25)))),
+        );
+        assert_eq!(tree.boundary(), vec![&20, &8, &4, &10, &14, &25, &22]);
+
+        let left_only = Node::new(1, Some(Node::new(2, None, Some(Node::leaf(3)))), None);
+        assert_eq!(left_only.boundary(), vec![&1, &2, &3]);
+
+        assert_eq!(Node::leaf(1).boundary(), vec![&1]);
+    }
+
+    #[test]
+    fn levels() {
+        let tree = BinaryTree::from_sorted(&[1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(
+            tree.root().unwrap().levels(),
+            vec![vec![&4], vec![&2, &6], vec![&1, &3, &5, &7]]
+        );
+
+        let tree = Node::from_shape(&[
+            (1, 0, Side::Left),
+            (2, 0, Side::Right),
+            (3, 1, Side::Left),
+            (4, 2, Side::Right),
+        ]);
+        assert_eq!(tree.levels(), vec![vec![&1], vec![&2], vec![&3], vec![&4]]);
+    }
+
+    #[test]
+    fn root_to_leaf_paths() {
+        let tree = Node::new(
+            4,
+            Some(Node::new(2, Some(Node::leaf(1)), Some(Node::leaf(3)))),
+            Some(Node::new(6, Some(Node::leaf(5)), Some(Node::leaf(7)))),
+        );
+        assert_eq!(
+            tree.root_to_leaf_paths(),
+            vec![
+                vec![&4, &2, &1],
+                vec![&4, &2, &3],
+                vec![&4, &6, &5],
+                vec![&4, &6, &7]
+            ]
+        );
+        assert_eq!(Node::leaf(1).root_to_leaf_paths(), vec![vec![&1]]);
+    }
+
+    #[test]
+    fn balance_factors() {
+        let tree = Node::new(
+            3,
+            Some(Node::new(2, Some(Node::leaf(1)), None)),
+            Some(Node::new(
+                4,
+                None,
+                Some(Node::new(5, None, Some(Node::leaf(6)))),
+            )),
+        );
+        assert_eq!(
+            tree.balance_factors(),
+            Node::new(
+                -1,
+                Some(Node::new(1, Some(Node::leaf(0)), None)),
+                Some(Node::new(
+                    -2,
+                    None,
+                    Some(Node::new(-1, None, Some(Node::leaf(0))))
+                )),
+            )
+        );
+        assert_eq!(Node::leaf(1).balance_factors(), Node::leaf(0));
+    }
+
+    #[test]
+    fn first_unbalanced() {
+        let tree = Node::from_shape(&[
+            (8, 0, Side::Left),
+            (4, 0, Side::Left),
+            (12, 0, Side::Right),
+            (2, 1, Side::Left),
+            (1, 3, Side::Left),
+            (10, 2, Side::Left),
+            (14, 2, Side::Right),
+            (13, 6, Side::Left),
+        ]);
+        assert_eq!(tree.first_unbalanced(), Some(&4));
+        assert!(!tree.is_balanced());
+
+        let tree = BinaryTree::from_sorted(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(tree.root().unwrap().first_unbalanced(), None);
+    }
+
+    #[test]
+    fn flatten_to_right() {
+        let mut tree = Node::new(
+            4,
+            Some(Node::new(2, Some(Node::leaf(1)), Some(Node::leaf(3)))),
+            Some(Node::leaf(6)),
+        );
+        let preorder = tree
+            .walk()
+            .filter_map(|event| match event {
+                TreeEvent::Enter(value) => Some(*value),
+                TreeEvent::Leave(_) => None,
+            })
+            .collect::<Vec<_>>();
+        tree.flatten_to_right();
+        assert_eq!(tree.iter_inorder().copied().collect::<Vec<_>>(), preorder);
+        assert_eq!(preorder, vec![4, 2, 1, 3, 6]);
+        assert_eq!(tree.height(), 5);
+        assert_eq!(tree.leaf_count(), 1);
+    }
+
+    #[test]
+    fn internal_path_length() {
+        let values = (1..=15).collect::<Vec<_>>();
+        let balanced = BinaryTree::from_sorted(&values);
+        // 2 * 1 + 4 * 2 + 8 * 3
+        assert_eq!(balanced.root().unwrap().internal_path_length(), 34);
+
+        let mut spine = balanced.root().unwrap().clone();
+        spine.flatten_to_right();
+        // 0 + 1 + ... + 14
+        assert_eq!(spine.internal_path_length(), 105);
+
+        assert_eq!(Node::leaf(1).internal_path_length(), 0);
+    }
+
+    #[test]
+    fn contains_subtree() {
+        let tree = Node::new(
+            4,
+            Some(Node::new(2, Some(Node::leaf(1)), Some(Node::leaf(3)))),
+            Some(Node::new(6, Some(Node::leaf(5)), None)),
+        );
+        let sub = Node::new(2, Some(Node::leaf(1)), Some(Node::leaf(3)));
+        assert!(tree.contains_subtree(&sub));
+        assert!(tree.contains_subtree(&tree));
+        assert!(tree.contains_subtree(&Node::leaf(5)));
+
+        let near_miss = Node::new(2, Some(Node::leaf(1)), Some(Node::leaf(4)));
+        assert!(!tree.contains_subtree(&near_miss));
+        // the subtree has to be complete, not just a prefix of a subtree
+        assert!(!tree.contains_subtree(&Node::leaf(6)));
+    }
+
+    #[test]
+    fn is_complete() {
+        let complete = Node::new(
+            1,
+            Some(Node::new(2, Some(Node::leaf(4)), Some(Node::leaf(5)))),
+            Some(Node::new(3, Some(Node::leaf(6)), None)),
+        );
+        assert!(complete.is_complete());
+
+        let gap = Node::new(
+            1,
+            Some(Node::new(2, Some(Node::leaf(4)), None)),
+            Some(Node::new(3, Some(Node::leaf(6)), None)),
+        );
+        assert!(!gap.is_complete());
+
+        let right_only = Node::new(1, None, Some(Node::leaf(2)));
+        assert!(!right_only.is_complete());
+
+        assert!(Node::leaf(1).is_complete());
+    }
+
+    #[test]
+    fn is_isomorphic_shape() {
+        let numbers = BinaryTree::from_sorted(&[1, 2, 3, 4, 5]);
+        let strings = BinaryTree::from_sorted(&["a", "b", "c", "d", "e"]);
+        let numbers = numbers.root().unwrap();
+        assert!(numbers.is_isomorphic_shape(strings.root().unwrap()));
+
+        let other = BinaryTree::from_sorted(&["a", "b", "c", "d"]);
+        assert!(!numbers.is_isomorphic_shape(other.root().unwrap()));
+        let mirrored = Node::new(1, None, Some(Node::leaf(2)));
+        assert!(!Node::new(1, Some(Node::leaf(2)), None).is_isomorphic_shape(&mirrored));
+        assert!(Node::leaf(1).is_isomorphic_shape(&Node::leaf('a')));
+    }
+
+    #[test]
+    fn leaves() {
+        let tree = Node::new(
+            4,
+            Some(Node::new(2, Some(Node::leaf(1)), Some(Node::leaf(3)))),
+            Some(Node::new(6, Some(Node::leaf(5)), Some(Node::leaf(7)))),
+        );
+        assert_eq!(tree.leaves().copied().collect::<Vec<_>>(), vec![1, 3, 5, 7]);
+
+        let tree = Node::new(1, Some(Node::leaf(2)), None);
+        assert_eq!(tree.leaves().copied().collect::<Vec<_>>(), vec![2]);
+
+        assert_eq!(Node::leaf(1).leaves().copied().collect::<Vec<_>>(), vec![1]);
+    }
+
+    #[test]
+    fn into_linked_list() {
+        // 4(2(1,3),6(5,7))
+        let tree = BinaryTree::from_sorted(&[1, 2, 3, 4, 5, 6, 7]);
+        let collect = |list: LinkedList<i32>| list.into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            collect(tree.clone().into_linked_list_inorder()),
+            vec![1, 2, 3, 4, 5, 6, 7]
+        );
+        assert_eq!(
+            collect(tree.clone().into_linked_list_preorder()),
+            vec![4, 2, 1, 3, 6, 5, 7]
+        );
+        assert_eq!(
+            collect(tree.clone().into_linked_list_postorder()),
+            vec![1, 3, 2, 5, 7, 6, 4]
+        );
+        assert_eq!(
+            collect(tree.into_linked_list_level_order()),
+            vec![4, 2, 6, 1, 3, 5, 7]
+        );
+        assert!(BinaryTree::<i32>::new()
+            .into_linked_list_inorder()
+            .is_empty());
+    }
+
+    #[test]
+    fn merge() {
+        let lhs = BinaryTree::from_sorted(&[1, 3, 5]);
+        let rhs = BinaryTree::from_sorted(&[2, 4, 6]);
+        let merged = lhs.merge(rhs);
+        let root = merged.root().unwrap();
+        assert!(root.is_search_tree());
+        assert!(root.is_balanced());
+        assert_eq!(
+            root.iter_inorder().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+
+        let merged = BinaryTree::from_sorted(&[1, 2]).merge(BinaryTree::from_sorted(&[2, 3]));
+        assert_eq!(
+            merged
+                .root()
+                .unwrap()
+                .iter_inorder()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(
+            BinaryTree::<i32>::new().merge(BinaryTree::new()),
+            BinaryTree::new()
+        );
+    }
+
+    #[test]
+    fn max_path_sum() {
+        // -10(9, 20(15, 7)): the best path is 15 -> 20 -> 7
+        let tree = Node::new(
+            -10,
+            Some(Node::leaf(9)),
+            Some(Node::new(20, Some(Node::leaf(15)), Some(Node::leaf(7)))),
+        );
+        assert_eq!(tree.max_path_sum(), 42);
+
+        // the negative subtree is left out
+        let tree = Node::new(
+            5,
+            Some(Node::new(-8, Some(Node::leaf(-1)), None)),
+            Some(Node::leaf(3)),
+        );
+        assert_eq!(tree.max_path_sum(), 8);
+        // the path goes through the negative root to connect both sides
+        let tree = Node::new(-1, Some(Node::leaf(4)), Some(Node::leaf(6)));
+        assert_eq!(tree.max_path_sum(), 9);
+        let tree = Node::new(-3, Some(Node::leaf(-2)), Some(Node::leaf(-5)));
+        assert_eq!(tree.max_path_sum(), -2);
+    }
+
+    #[test]
+    fn reduce() {
+        let tree = Node::from_shape(&[
+            (1, 0, Side::Left),
+            (2, 0, Side::Left),
+            (3, 0, Side::Right),
+            (4, 2, Side::Left),
+            (5, 3, Side::Left),
+        ]);
+        let height =
+            tree.reduce(|_, lhs: Option<usize>, rhs| lhs.unwrap_or(0).max(rhs.unwrap_or(0)) + 1);
+        assert_eq!(height, tree.height());
+        let sum = tree.reduce(|value, lhs, rhs| value + lhs.unwrap_or(0) + rhs.unwrap_or(0));
+        assert_eq!(sum, tree.sum());
+        assert_eq!(sum, 15);
+        assert_eq!(Node::leaf(7).reduce(|value, _, _: Option<i32>| *value), 7);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        let tree = BinaryTree::from_sorted(&[1, 2, 3, 4, 5, 6, 7]);
+        let json = serde_json::to_string(&tree).unwrap();
+        assert_eq!(
+            serde_json::from_str::<BinaryTree<i32>>(&json).unwrap(),
+            tree
+        );
+
+        // the shape is kept even if the tree is not balanced
+        let node =
+            Node::from_shape(&[(1, 0, Side::Left), (2, 0, Side::Right), (3, 1, Side::Right)]);
+        let json = serde_json::to_string(&node).unwrap();
+        assert_eq!(
+            json,
+            r#"{"left":null,"value":1,"right":{"left":null,"value":2,"right":{"left":null,"value":3,"right":null}}}"#
+        );
+        assert_eq!(serde_json::from_str::<Node<i32>>(&json).unwrap(), node);
+    }
+
+    #[test]
+    fn display_padded() {
+        let tree = Node::new(1, Some(Node::leaf(100)), Some(Node::leaf(5)));
+        assert_eq!(
+            tree.display_padded(3),
+            "        1\n     /     \\\n100           5\n"
+        );
+        assert_eq!(tree.display_padded(0), tree.display());
+
+        let tree = Node::new(
+            1,
+            Some(Node::new(
+                1000,
+                Some(Node::leaf(123456)),
+                Some(Node::leaf(7)),
+            )),
+            Some(Node::leaf(5)),
+        );
+        assert_eq!(tree.display_padded(0), tree.display());
+    }
 }
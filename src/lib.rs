@@ -5,4 +5,7 @@ pub mod linked_list;
 pub mod packed_linked_list;
 
 /// A binary tree that can be printed
-mod binary_tree;
+pub mod binary_tree;
+
+/// Allocation helpers shared by the lists
+mod alloc;
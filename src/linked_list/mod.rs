@@ -1,12 +1,39 @@
 #[cfg(test)]
 mod test;
 
+use crate::alloc::try_allocate_nonnull;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, TryReserveError};
+use std::convert::TryFrom;
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
 use std::iter::FromIterator;
 use std::marker::PhantomData;
+use std::mem;
+use std::mem::MaybeUninit;
+use std::ops::{Add, Sub};
 use std::ptr::NonNull;
 
+/// Creates a [LinkedList] containing the arguments, like `vec![]`
+///
+/// ```
+/// # use datastructures::linked_list;
+/// #
+/// let list = linked_list![1, 2, 3];
+/// assert_eq!(list.get(2), Some(&3));
+/// ```
+#[macro_export]
+macro_rules! linked_list {
+    () => {
+        $crate::linked_list::LinkedList::new()
+    };
+    ($($item:expr),+ $(,)?) => {{
+        let mut list = $crate::linked_list::LinkedList::new();
+        $(list.push_back($item);)+
+        list
+    }};
+}
+
 /// A doubly linked list using unsafe code.  
 /// It is loosely inspired by the `std::collections::LinkedList`, but I haven't looked at that one too close,
 /// so most it is my own.
@@ -46,6 +73,9 @@ use std::ptr::NonNull;
 pub struct LinkedList<T> {
     start: Option<NonNull<Node<T>>>,
     end: Option<NonNull<Node<T>>>,
+    /// Nodes kept by [LinkedList::clear_retaining_allocations] for reuse, linked through their `next` pointer.
+    /// Their values have already been dropped
+    pool: Option<NonNull<Node<T>>>,
     _marker: PhantomData<T>,
 }
 
@@ -55,17 +85,51 @@ impl<T> LinkedList<T> {
         Self {
             start: None,
             end: None,
+            pool: None,
             _marker: PhantomData,
         }
     }
 
+    /// Creates a list from a range or any other iterator, which is the same as collecting it
+    ///
+    /// For ranges of `usize`, see [LinkedList::range]
+    pub fn from_range<R: IntoIterator<Item = T>>(range: R) -> Self {
+        range.into_iter().collect()
+    }
+
+    /// Creates a list from an iterator in reversed order, the first item of the iterator will be the last in the list
+    pub fn from_iter_rev<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        for item in iter {
+            list.push_front(item);
+        }
+        list
+    }
+
     /// Push an element to the start of the list, O(1)
     pub fn push_front(&mut self, element: T) {
-        let new_node = allocate_nonnull(Node {
+        let new_node = self.allocate_node(Node {
             value: element,
             next: self.start,
             prev: None,
         });
+        self.link_front(new_node);
+    }
+
+    /// Push an element to the start of the list, O(1)
+    ///
+    /// Returns an error instead of aborting if the node can't be allocated
+    pub fn try_push_front(&mut self, element: T) -> Result<(), TryReserveError> {
+        let new_node = self.try_allocate_node(Node {
+            value: element,
+            next: self.start,
+            prev: None,
+        })?;
+        self.link_front(new_node);
+        Ok(())
+    }
+
+    fn link_front(&mut self, new_node: NonNull<Node<T>>) {
         match self.start {
             Some(mut old_start) => {
                 // SAFETY: All pointers should always be valid.
@@ -79,11 +143,41 @@ impl<T> LinkedList<T> {
 
     /// Push an element to the end of the list, O(1)
     pub fn push_back(&mut self, element: T) {
-        let new_node = allocate_nonnull(Node {
+        let new_node = self.allocate_node(Node {
             value: element,
             next: None,
             prev: self.end,
         });
+        self.link_back(new_node);
+    }
+
+    /// Push an element to the end of the list and returns the amount of inversions that it introduces,
+    /// which is the amount of elements already in the list that are greater than it
+    ///
+    /// This scans the whole list, so it is O(n)
+    pub fn push_back_counting_inversions(&mut self, element: T) -> usize
+    where
+        T: Ord,
+    {
+        let inversions = self.iter().filter(|value| **value > element).count();
+        self.push_back(element);
+        inversions
+    }
+
+    /// Push an element to the end of the list, O(1)
+    ///
+    /// Returns an error instead of aborting if the node can't be allocated
+    pub fn try_push_back(&mut self, element: T) -> Result<(), TryReserveError> {
+        let new_node = self.try_allocate_node(Node {
+            value: element,
+            next: None,
+            prev: self.end,
+        })?;
+        self.link_back(new_node);
+        Ok(())
+    }
+
+    fn link_back(&mut self, new_node: NonNull<Node<T>>) {
         match self.end {
             Some(mut old_end) => {
                 // SAFETY: All pointers should always be valid.
@@ -95,6 +189,57 @@ impl<T> LinkedList<T> {
         self.end = Some(new_node);
     }
 
+    /// Allocates a new node, reusing a node retained by [LinkedList::clear_retaining_allocations] if possible
+    fn allocate_node(&mut self, node: Node<T>) -> NonNull<Node<T>> {
+        match self.pool {
+            Some(pooled) => {
+                // SAFETY: pooled nodes are valid allocations whose values have already been dropped
+                unsafe {
+                    self.pool = pooled.as_ref().next;
+                    pooled.as_ptr().write(node);
+                }
+                pooled
+            }
+            None => allocate_nonnull(node),
+        }
+    }
+
+    /// Like [LinkedList::allocate_node], but returns an error instead of aborting if the allocation fails
+    fn try_allocate_node(&mut self, node: Node<T>) -> Result<NonNull<Node<T>>, TryReserveError> {
+        match self.pool {
+            Some(_) => Ok(self.allocate_node(node)),
+            None => try_allocate_nonnull(node),
+        }
+    }
+
+    /// Removes all elements from the list, but keeps the allocations of the nodes to reuse them for
+    /// elements pushed later. The retained nodes are freed when the list is dropped
+    pub fn clear_retaining_allocations(&mut self) {
+        let mut item = self.start.take();
+        self.end = None;
+        while let Some(mut content) = item {
+            // SAFETY: All pointers should always be valid, the value is dropped exactly once here
+            unsafe {
+                let node = content.as_mut();
+                item = node.next;
+                std::ptr::drop_in_place(&mut node.value);
+                node.next = self.pool;
+            }
+            self.pool = Some(content);
+        }
+    }
+
+    /// Frees the nodes retained by [LinkedList::clear_retaining_allocations]
+    fn free_pool(&mut self) {
+        while let Some(node) = self.pool {
+            // SAFETY: pooled nodes are created from a box, the value must not be dropped again
+            unsafe {
+                self.pool = node.as_ref().next;
+                drop(Box::from_raw(node.as_ptr() as *mut MaybeUninit<Node<T>>));
+            }
+        }
+    }
+
     /// Pops the first value in the list and returns it, O(1)
     pub fn pop_front(&mut self) -> Option<T> {
         self.start.map(|node| {
@@ -133,6 +278,54 @@ impl<T> LinkedList<T> {
         })
     }
 
+    /// Removes the `n`-th element counted from the back and returns it, so an `n` of 1 removes the last element.
+    /// Returns `None` and leaves the list unchanged if `n` is 0 or larger than the length
+    ///
+    /// This only walks the list once, without calculating the length first
+    pub fn remove_nth_from_end(&mut self, n: usize) -> Option<T> {
+        if n == 0 {
+            return None;
+        }
+        // SAFETY: All pointers should always be valid
+        let next = |node: NonNull<Node<T>>| unsafe { node.as_ref() }.next;
+        // the lead is `n` nodes ahead of the target, so the target is the `n`-th node from the back
+        // once the lead has walked past the end
+        let mut lead = self.start;
+        for _ in 0..n {
+            lead = next(lead?);
+        }
+        let mut target = self.start?;
+        while let Some(node) = lead {
+            lead = next(node);
+            target = next(target)?;
+        }
+
+        // SAFETY: All pointers should always be valid, and the node was created from a box
+        unsafe {
+            let content = target.as_ref();
+            match content.prev {
+                Some(mut prev) => prev.as_mut().next = content.next,
+                None => self.start = content.next,
+            }
+            match content.next {
+                Some(mut next) => next.as_mut().prev = content.prev,
+                None => self.end = content.prev,
+            }
+            Some(Box::from_raw(target.as_ptr()).value)
+        }
+    }
+
+    /// Pops the first value in the list only if it matches the predicate, O(1)
+    ///
+    /// Returns `None` and leaves the list unchanged if the list is empty or the value doesn't match
+    pub fn pop_front_if<F: FnOnce(&T) -> bool>(&mut self, f: F) -> Option<T> {
+        if self.get_head().is_some_and(f) {
+            self.pop_front()
+        } else {
+            None
+        }
+    }
+
     /// Get an element from the list, O(n)
     pub fn get(&self, mut index: usize) -> Option<&T> {
         let mut node = &self.start;
@@ -160,6 +353,20 @@ impl<T> LinkedList<T> {
         self.start.as_ref().map(|nn| unsafe { &nn.as_ref().value })
     }
 
+    /// Gets the first element from the list mutably, O(1)
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.start
+            .as_mut()
+            .map(|nn| unsafe { &mut nn.as_mut().value })
+    }
+
+    /// Gets the last element from the list mutably, O(1)
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.end
+            .as_mut()
+            .map(|nn| unsafe { &mut nn.as_mut().value })
+    }
+
     /// Get a node from the list that can only be used for navigation, O(n)
     pub fn get_node(&self, mut index: usize) -> Option<&Node<T>> {
         let mut node = &self.start;
@@ -229,15 +436,734 @@ impl<T> LinkedList<T> {
         self.len() == 0
     }
 
+    /// Checks whether both lists share the same first node allocation, which is only the case if they are the same list
+    ///
+    /// Clones always get their own nodes, so this is false for a list and its clone. An empty list is only
+    /// `ptr_eq` to itself.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (self.start, other.start) {
+            (Some(a), Some(b)) => a == b,
+            _ => std::ptr::eq(self, other),
+        }
+    }
+
     /// Returns an iterator over the items
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter::new(self)
     }
 
     /// Returns a mut iterator over the items
-    pub fn iter_mut(&mut self) -> IterMut<T> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         IterMut::new(self)
     }
+
+    /// Makes the element at `index` the new head of the list while keeping the cyclic order of the elements.
+    /// Only the nodes at the cut are relinked, no values are moved, so this is O(1) after reaching the node
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds
+    pub fn rotate_to_front(&mut self, mut index: usize) {
+        let mut node = self.start;
+        while index > 0 {
+            // SAFETY: All pointers should always be valid
+            node = node.and_then(|node| unsafe { node.as_ref() }.next);
+            index -= 1;
+        }
+        let mut new_start = node.expect("index out of bounds");
+        // SAFETY: All pointers should always be valid
+        let mut new_end = match unsafe { new_start.as_ref() }.prev {
+            Some(prev) => prev,
+            // the element is already the head
+            None => return,
+        };
+        // the list contains at least two elements here, so start and end are set
+        let (mut old_start, mut old_end) = (self.start.unwrap(), self.end.unwrap());
+        // SAFETY: All pointers should always be valid
+        unsafe {
+            old_end.as_mut().next = Some(old_start);
+            old_start.as_mut().prev = Some(old_end);
+            new_start.as_mut().prev = None;
+            new_end.as_mut().next = None;
+        }
+        self.start = Some(new_start);
+        self.end = Some(new_end);
+    }
+
+    /// Moves the element at `index` to the front of the list, keeping the order of the other elements.
+    /// Reaching the node is O(n), relinking it is O(1)
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds
+    pub fn move_to_front(&mut self, mut index: usize) {
+        let mut node = self.start;
+        while index > 0 {
+            // SAFETY: All pointers should always be valid
+            node = node.and_then(|node| unsafe { node.as_ref() }.next);
+            index -= 1;
+        }
+        let mut node = node.expect("index out of bounds");
+        // SAFETY: All pointers should always be valid
+        unsafe {
+            let mut prev = match node.as_ref().prev {
+                Some(prev) => prev,
+                // the node is already at the front
+                None => return,
+            };
+            let next = node.as_ref().next;
+            prev.as_mut().next = next;
+            match next {
+                Some(mut next) => next.as_mut().prev = Some(prev),
+                None => self.end = Some(prev),
+            }
+
+            // the list has at least two elements, so there is a start
+            let mut start = self.start.unwrap();
+            start.as_mut().prev = Some(node);
+            node.as_mut().next = Some(start);
+            node.as_mut().prev = None;
+            self.start = Some(node);
+        }
+    }
+
+    /// Rotates the list to the left by `n` elements, so that the element at `n` becomes the new head.
+    /// `n` can be larger than the length of the list, it wraps around. An empty list is left unchanged
+    ///
+    /// See [LinkedList::rotate_to_front]
+    pub fn rotate_left_wrapping(&mut self, n: usize) {
+        let len = self.len();
+        if len != 0 {
+            self.rotate_to_front(n % len);
+        }
+    }
+
+    /// Rotates the list to the right by `n` elements, so that the last `n` elements are moved to the front.
+    /// `n` can be larger than the length of the list, it wraps around. An empty list is left unchanged
+    ///
+    /// See [LinkedList::rotate_to_front]
+    pub fn rotate_right_wrapping(&mut self, n: usize) {
+        let len = self.len();
+        if len != 0 {
+            self.rotate_to_front((len - n % len) % len);
+        }
+    }
+
+    /// Returns the middle element of the list in a single pass, without calculating the length first
+    ///
+    /// For lists with an even length, the first of the two middle elements is returned
+    pub fn middle(&self) -> Option<&T> {
+        let mut slow = self.front_node()?;
+        let mut fast = slow;
+        while let Some(next) = fast.next().and_then(Node::next) {
+            fast = next;
+            // the slow node is always behind the fast node, so it has a next node
+            slow = slow.next().unwrap();
+        }
+        Some(slow.get())
+    }
+
+    /// Checks whether following the `next` links from the first node loops forever, which can happen if the nodes
+    /// were linked in an invalid way. This doesn't allocate and always terminates
+    ///
+    /// A list with a cycle can't be iterated over, since iteration would never end
+    pub fn has_cycle(&self) -> bool {
+        let mut slow = self.start;
+        let mut fast = self.start;
+        // SAFETY: All pointers should always point to valid memory
+        let next = |node: NonNull<Node<T>>| unsafe { (*node.as_ptr()).next };
+        while let Some(fast_node) = fast {
+            fast = match next(fast_node) {
+                Some(node) => next(node),
+                None => return false,
+            };
+            slow = slow.and_then(next);
+            if fast.is_some() && fast == slow {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Checks whether the node is part of this list by comparing it with all nodes of the list, O(n)
+    pub fn contains_node(&self, node: &Node<T>) -> bool {
+        let mut current = self.front_node();
+        while let Some(content) = current {
+            if std::ptr::eq(content, node) {
+                return true;
+            }
+            current = content.next();
+        }
+        false
+    }
+
+    /// Reverses the order of the elements from `start` (inclusive) to `end` (exclusive) by relinking the nodes,
+    /// the elements outside of the range are not touched
+    ///
+    /// # Panics
+    /// Panics if `start > end` or `end` is larger than the length of the list
+    pub fn reverse_range(&mut self, start: usize, end: usize) {
+        let (first, last, _) = match self.node_range(start, end) {
+            Some(range) => range,
+            None => return,
+        };
+
+        // SAFETY: `first` comes before `last` in this list
+        unsafe { self.reverse_nodes(first, last) };
+    }
+
+    /// Removes the elements from `start` (inclusive) to `end` (exclusive) and returns them as a new list.
+    /// The nodes are relinked into the new list, so the elements are not moved
+    ///
+    /// # Panics
+    /// Panics if `start > end` or `end` is larger than the length of the list
+    pub fn splice_out(&mut self, start: usize, end: usize) -> LinkedList<T> {
+        let (mut first, mut last, after) = match self.node_range(start, end) {
+            Some(range) => range,
+            None => return LinkedList::new(),
+        };
+
+        // SAFETY: All pointers should always be valid
+        unsafe {
+            let before = first.as_ref().prev;
+            match before {
+                Some(mut before) => before.as_mut().next = after,
+                None => self.start = after,
+            }
+            match after {
+                Some(mut after) => after.as_mut().prev = before,
+                None => self.end = before,
+            }
+            first.as_mut().prev = None;
+            last.as_mut().next = None;
+        }
+
+        LinkedList {
+            start: Some(first),
+            end: Some(last),
+            pool: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the first and the last node of the elements from `start` (inclusive) to `end` (exclusive),
+    /// and the node after them. Returns `None` if the range is empty
+    ///
+    /// # Panics
+    /// Panics if `start > end` or `end` is larger than the length of the list
+    #[allow(clippy::type_complexity)]
+    fn node_range(
+        &self,
+        start: usize,
+        end: usize,
+    ) -> Option<(NonNull<Node<T>>, NonNull<Node<T>>, Option<NonNull<Node<T>>>)> {
+        assert!(
+            start <= end,
+            "the start of the range must not be after the end"
+        );
+        let mut node = self.start;
+        let mut first = None;
+        for index in 0..end {
+            let current = node.expect("range out of bounds");
+            if index == start {
+                first = Some(current);
+            }
+            // SAFETY: All pointers should always be valid
+            node = unsafe { current.as_ref() }.next;
+        }
+        // the range is empty
+        let first = first?;
+        let last = match node {
+            // SAFETY: All pointers should always be valid
+            Some(after) => unsafe { after.as_ref() }.prev.unwrap(),
+            None => self.end.unwrap(),
+        };
+        Some((first, last, node))
+    }
+
+    /// Inserts all elements of `other` before the element at index `at`, or at the end if `at` is the length of
+    /// the list. The nodes of `other` are relinked into this list, so this is O(at)
+    ///
+    /// # Panics
+    /// Panics if `at` is larger than the length of the list
+    pub fn splice_in(&mut self, at: usize, mut other: LinkedList<T>) {
+        let mut after = self.start;
+        for _ in 0..at {
+            let current = after.expect("index out of bounds");
+            // SAFETY: All pointers should always be valid
+            after = unsafe { current.as_ref() }.next;
+        }
+        let (mut first, mut last) = match (other.start.take(), other.end.take()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return,
+        };
+
+        // SAFETY: All pointers should always be valid, the nodes of `other` are owned by this list now
+        unsafe {
+            let before = match after {
+                Some(after) => after.as_ref().prev,
+                None => self.end,
+            };
+            first.as_mut().prev = before;
+            last.as_mut().next = after;
+            match before {
+                Some(mut before) => before.as_mut().next = Some(first),
+                None => self.start = Some(first),
+            }
+            match after {
+                Some(mut after) => after.as_mut().prev = Some(last),
+                None => self.end = Some(last),
+            }
+        }
+    }
+
+    /// Reverses each consecutive group of `k` elements by relinking the nodes. If the last group is smaller than
+    /// `k`, it is only reversed if `reverse_remainder` is true. A `k` of 0 or 1 leaves the list unchanged
+    pub fn reverse_in_groups(&mut self, k: usize, reverse_remainder: bool) {
+        if k < 2 {
+            return;
+        }
+        let mut group_start = self.start;
+        while let Some(first) = group_start {
+            let mut last = first;
+            let mut size = 1;
+            while size < k {
+                // SAFETY: All pointers should always be valid
+                match unsafe { last.as_ref() }.next {
+                    Some(next) => {
+                        last = next;
+                        size += 1;
+                    }
+                    None => break,
+                }
+            }
+            group_start = unsafe { last.as_ref() }.next;
+            if size == k || reverse_remainder {
+                // SAFETY: `first` comes before `last` in this list
+                unsafe { self.reverse_nodes(first, last) };
+            }
+        }
+    }
+
+    /// Reverses the nodes from `first` to `last` (both inclusive) by relinking them
+    ///
+    /// # Safety
+    /// `first` and `last` must be nodes of this list, and `first` must not come after `last`
+    unsafe fn reverse_nodes(&mut self, mut first: NonNull<Node<T>>, mut last: NonNull<Node<T>>) {
+        let before = first.as_ref().prev;
+        let after = last.as_ref().next;
+
+        let mut current = Some(first);
+        while let Some(mut node) = current {
+            let node = node.as_mut();
+            current = if node.next == after { None } else { node.next };
+            mem::swap(&mut node.next, &mut node.prev);
+        }
+
+        first.as_mut().next = after;
+        last.as_mut().prev = before;
+        match before {
+            Some(mut before) => before.as_mut().next = Some(last),
+            None => self.start = Some(last),
+        }
+        match after {
+            Some(mut after) => after.as_mut().prev = Some(first),
+            None => self.end = Some(first),
+        }
+    }
+
+    /// Merges sorted lists into a single sorted list in O(n log k) by relinking their nodes. Of equal elements,
+    /// the ones from earlier lists come first
+    ///
+    /// The lists are expected to be sorted, otherwise the order of the result is unspecified
+    pub fn merge_k_sorted<I: IntoIterator<Item = LinkedList<T>>>(lists: I) -> LinkedList<T>
+    where
+        T: Ord,
+    {
+        let mut heads = MergeHeads(BinaryHeap::new());
+        for (list_index, mut list) in lists.into_iter().enumerate() {
+            // the nodes are owned by the heap now, the list is empty and only frees its node pool
+            list.end = None;
+            if let Some(node) = list.start.take() {
+                heads.0.push(MergeHead { node, list_index });
+            }
+        }
+
+        let mut merged = LinkedList::new();
+        while let Some(MergeHead {
+            mut node,
+            list_index,
+        }) = heads.0.pop()
+        {
+            // SAFETY: All pointers should always be valid, the node is only owned by the heap
+            let next = unsafe {
+                let node = node.as_mut();
+                node.prev = merged.end;
+                node.next.take()
+            };
+            merged.link_back(node);
+            if let Some(next) = next {
+                heads.0.push(MergeHead {
+                    node: next,
+                    list_index,
+                });
+            }
+        }
+        merged
+    }
+
+    /// Compresses runs of consecutive equal elements into a single `(value, count)` element, keeping the first
+    /// value of each run
+    pub fn run_length_encode(self) -> LinkedList<(T, usize)>
+    where
+        T: PartialEq,
+    {
+        let mut encoded = LinkedList::new();
+        for item in self {
+            match encoded.last_mut() {
+                Some((value, count)) if *value == item => *count += 1,
+                _ => encoded.push_back((item, 1)),
+            }
+        }
+        encoded
+    }
+
+    /// Combines the elements of both lists into a list of pairs. The result is as long as the shorter list,
+    /// the remaining elements of the longer list are dropped
+    pub fn zip<U>(self, other: LinkedList<U>) -> LinkedList<(T, U)> {
+        self.into_iter().zip(other).collect()
+    }
+
+    /// Weaves the elements of `other` into this list, alternating between an element of this list and one of
+    /// `other`. The remaining elements of the longer list are appended at the end.
+    /// The nodes are relinked, so no elements are cloned or moved
+    pub fn interleave(&mut self, mut other: LinkedList<T>) {
+        let mut theirs = other.start.take();
+        let their_end = other.end.take();
+        let mut ours = self.start;
+        if ours.is_none() {
+            self.start = theirs;
+            self.end = their_end;
+            return;
+        }
+        // SAFETY: All pointers should always be valid
+        unsafe {
+            while let (Some(mut our_node), Some(mut their_node)) = (ours, theirs) {
+                let our_next = our_node.as_ref().next;
+                let their_next = their_node.as_ref().next;
+                our_node.as_mut().next = Some(their_node);
+                their_node.as_mut().prev = Some(our_node);
+                match our_next {
+                    Some(mut our_next) => {
+                        their_node.as_mut().next = Some(our_next);
+                        our_next.as_mut().prev = Some(their_node);
+                    }
+                    None => {
+                        // the remaining nodes of `other` are still linked to this node
+                        self.end = their_end;
+                        return;
+                    }
+                }
+                ours = our_next;
+                theirs = their_next;
+            }
+        }
+    }
+
+    /// Checks whether the elements of the list are sorted in ascending order
+    ///
+    /// See [LinkedList::is_sorted_by]
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
+
+    /// Checks whether the elements of the list are sorted using the given comparator.
+    /// `compare` is called with every pair of adjacent elements and should return `true` if they are in order.
+    /// Stops at the first pair that is out of order
+    pub fn is_sorted_by<F: FnMut(&T, &T) -> bool>(&self, mut compare: F) -> bool {
+        let mut iter = self.iter();
+        let mut prev = match iter.next() {
+            Some(first) => first,
+            None => return true,
+        };
+        for current in iter {
+            if !compare(prev, current) {
+                return false;
+            }
+            prev = current;
+        }
+        true
+    }
+
+    /// The length of the longest run of adjacent elements that are strictly increasing, in a single pass
+    ///
+    /// An empty list has a longest run of 0, every other list has one of at least 1
+    pub fn longest_increasing_run(&self) -> usize
+    where
+        T: PartialOrd,
+    {
+        let mut iter = self.iter();
+        let mut prev = match iter.next() {
+            Some(first) => first,
+            None => return 0,
+        };
+        let mut longest = 1;
+        let mut current_run = 1;
+        for current in iter {
+            if prev < current {
+                current_run += 1;
+                longest = longest.max(current_run);
+            } else {
+                current_run = 1;
+            }
+            prev = current;
+        }
+        longest
+    }
+
+    /// Returns a list of the differences between every element and the one before it, starting with the first
+    /// element itself. Summing up the differences gives back the original elements
+    pub fn deltas(&self) -> LinkedList<T>
+    where
+        T: Sub<Output = T> + Copy,
+    {
+        let mut prev = None;
+        self.iter()
+            .map(|&item| {
+                let delta = match prev {
+                    Some(prev) => item - prev,
+                    None => item,
+                };
+                prev = Some(item);
+                delta
+            })
+            .collect()
+    }
+
+    /// Treats the list as differences like the ones returned by [LinkedList::deltas] and returns the list of the
+    /// running sums, which are the original elements
+    pub fn from_deltas(&self) -> LinkedList<T>
+    where
+        T: Add<Output = T> + Copy,
+    {
+        let mut sum = None;
+        self.iter()
+            .map(|&delta| {
+                let item = match sum {
+                    Some(sum) => sum + delta,
+                    None => delta,
+                };
+                sum = Some(item);
+                item
+            })
+            .collect()
+    }
+
+    /// Returns the median element, which is the lower one of the two middle elements for lists with an even length
+    ///
+    /// References to the elements are collected into a `Vec` to select the median in O(n), so the order of the list
+    /// is not changed
+    pub fn median(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        let mut values = self.iter().collect::<Vec<_>>();
+        if values.is_empty() {
+            return None;
+        }
+        let middle = (values.len() - 1) / 2;
+        Some(*values.select_nth_unstable(middle).1)
+    }
+
+    /// Counts the distinct values in the list by sorting references to them and counting the unique ones
+    ///
+    /// This is O(n log n), for large lists, collecting the values into a `HashSet` might be faster
+    pub fn count_distinct_sorted(&self) -> usize
+    where
+        T: Ord,
+    {
+        let mut values = self.iter().collect::<Vec<_>>();
+        values.sort_unstable();
+        values.dedup();
+        values.len()
+    }
+
+    /// Walks the list and collects the accumulated value after every element, like [Iterator::scan]
+    ///
+    /// The value for an element is calculated from the value of the previous element (or `init` for the first one)
+    /// and the element itself, so this can for example be used to calculate prefix sums
+    pub fn scan_into_vec<B, F: FnMut(&B, &T) -> B>(&self, init: B, mut f: F) -> Vec<B> {
+        let mut values = Vec::new();
+        for item in self.iter() {
+            let next = f(values.last().unwrap_or(&init), item);
+            values.push(next);
+        }
+        values
+    }
+
+    /// Checks whether the list reads the same from the front and from the back
+    ///
+    /// This walks inwards from both ends at the same time, so it is O(n) without changing or copying the list
+    pub fn is_palindrome(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        let (mut front, mut back) = match (self.start, self.end) {
+            (Some(front), Some(back)) => (front, back),
+            _ => return true,
+        };
+        // SAFETY: All pointers should always be valid
+        unsafe {
+            while front != back {
+                if front.as_ref().value != back.as_ref().value {
+                    return false;
+                }
+                // the two nodes are neighbours, so they met in the middle of an even length list
+                if front.as_ref().next == Some(back) {
+                    break;
+                }
+                front = front.as_ref().next.unwrap();
+                back = back.as_ref().prev.unwrap();
+            }
+        }
+        true
+    }
+
+    /// Returns an iterator over the items that skips consecutive repeated elements, yielding only the first one
+    /// of each run. Unlike a `dedup`, this doesn't change the list
+    pub fn dedup_iter(&self) -> impl Iterator<Item = &T>
+    where
+        T: PartialEq,
+    {
+        let mut iter = self.iter();
+        let mut prev = None;
+        std::iter::from_fn(move || {
+            let item = iter.find(|item| prev.is_none_or(|prev| prev != *item))?;
+            prev = Some(item);
+            Some(item)
+        })
+    }
+
+    /// Returns an iterator over groups of `chunk_size` references to the items, the last one might be smaller
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is 0
+    pub fn chunks(&self, chunk_size: usize) -> impl Iterator<Item = Vec<&T>> {
+        assert_ne!(chunk_size, 0, "chunk_size must not be 0");
+        let mut iter = self.iter();
+        std::iter::from_fn(move || {
+            let chunk = iter.by_ref().take(chunk_size).collect::<Vec<_>>();
+            if chunk.is_empty() {
+                None
+            } else {
+                Some(chunk)
+            }
+        })
+    }
+
+    /// Splits the list into lists of `chunk_size` elements each, the last one might be smaller.
+    /// The nodes are relinked, so no elements are cloned or moved
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is 0
+    pub fn split_into_chunks(mut self, chunk_size: usize) -> Vec<LinkedList<T>> {
+        assert_ne!(chunk_size, 0, "chunk_size must not be 0");
+        let mut chunks = Vec::new();
+        let mut next = self.start.take();
+        self.end = None;
+        while let Some(start) = next {
+            let mut end = start;
+            for _ in 1..chunk_size {
+                // SAFETY: All pointers should always be valid
+                match unsafe { end.as_ref() }.next {
+                    Some(node) => end = node,
+                    None => break,
+                }
+            }
+            // SAFETY: All pointers should always be valid
+            next = unsafe { end.as_mut() }.next.take();
+            if let Some(mut next) = next {
+                unsafe { next.as_mut() }.prev = None;
+            }
+            chunks.push(LinkedList {
+                start: Some(start),
+                end: Some(end),
+                pool: None,
+                _marker: PhantomData,
+            });
+        }
+        chunks
+    }
+
+    /// Returns an iterator that removes and yields all elements matching the predicate.
+    /// Elements not matching the predicate stay in the list in their original order.
+    ///
+    /// If the iterator is dropped before it is exhausted, the remaining elements are left in the list.
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, pred: F) -> ExtractIf<'_, T, F> {
+        ExtractIf {
+            current: self.start,
+            list: self,
+            pred,
+        }
+    }
+}
+
+impl LinkedList<usize> {
+    /// Creates a list of the numbers from `start` (inclusive) to `end` (exclusive)
+    ///
+    /// The nodes are linked to each other right away, the start and end of the list are only set once
+    pub fn range(start: usize, end: usize) -> Self {
+        let mut list = Self::new();
+        let mut prev: Option<NonNull<Node<usize>>> = None;
+        for value in start..end {
+            let node = allocate_nonnull(Node {
+                value,
+                next: None,
+                prev,
+            });
+            match prev {
+                // SAFETY: the previous node was just allocated and is only referenced here
+                Some(mut prev) => unsafe { prev.as_mut() }.next = Some(node),
+                None => list.start = Some(node),
+            }
+            prev = Some(node);
+        }
+        list.end = prev;
+        list
+    }
+}
+
+impl<T> LinkedList<LinkedList<T>> {
+    /// Concatenates all inner lists into a single list by relinking their nodes, so the elements are not moved
+    pub fn flatten(self) -> LinkedList<T> {
+        let mut flat = LinkedList::new();
+        for mut inner in self {
+            let (mut first, last) = match (inner.start.take(), inner.end.take()) {
+                (Some(first), Some(last)) => (first, last),
+                // the list is empty
+                _ => continue,
+            };
+            // SAFETY: All pointers should always be valid, the nodes of `inner` are owned by the flat list now
+            unsafe { first.as_mut().prev = flat.end };
+            flat.link_back(first);
+            flat.end = Some(last);
+        }
+        flat
+    }
+}
+
+impl<T> LinkedList<(T, usize)> {
+    /// Expands every `(value, count)` element into `count` copies of the value, which reverses
+    /// [LinkedList::run_length_encode]
+    pub fn run_length_decode(self) -> LinkedList<T>
+    where
+        T: Clone,
+    {
+        self.into_iter()
+            .flat_map(|(value, count)| std::iter::repeat_n(value, count))
+            .collect()
+    }
 }
 
 /////
@@ -308,6 +1234,21 @@ impl<T> Extend<T> for LinkedList<T> {
     }
 }
 
+/// Converts the list into an array if it contains exactly `N` elements, otherwise the list is returned unchanged
+impl<T, const N: usize> TryFrom<LinkedList<T>> for [T; N] {
+    type Error = LinkedList<T>;
+
+    fn try_from(list: LinkedList<T>) -> Result<Self, Self::Error> {
+        if list.len() != N {
+            return Err(list);
+        }
+        match <[T; N]>::try_from(list.into_iter().collect::<Vec<_>>()) {
+            Ok(array) => Ok(array),
+            Err(_) => unreachable!("the length was checked before"),
+        }
+    }
+}
+
 impl<T> Drop for LinkedList<T> {
     fn drop(&mut self) {
         let mut item = self.start;
@@ -315,7 +1256,62 @@ impl<T> Drop for LinkedList<T> {
             // SAFETY: All pointers should always be valid and created from a box
             unsafe {
                 item = content.as_ref().next;
-                Box::from_raw(content.as_ptr());
+                drop(Box::from_raw(content.as_ptr()));
+            }
+        }
+        self.free_pool();
+    }
+}
+
+/// The first node that has not been merged yet of one of the lists in [LinkedList::merge_k_sorted]
+///
+/// It is ordered in reverse, so that the [BinaryHeap] returns the smallest value first, and from equal values
+/// the one from the earliest list
+struct MergeHead<T> {
+    node: NonNull<Node<T>>,
+    list_index: usize,
+}
+
+impl<T: Ord> Ord for MergeHead<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // SAFETY: All pointers should always be valid
+        let (value, other_value) =
+            unsafe { (&self.node.as_ref().value, &other.node.as_ref().value) };
+        other_value
+            .cmp(value)
+            .then_with(|| other.list_index.cmp(&self.list_index))
+    }
+}
+
+impl<T: Ord> PartialOrd for MergeHead<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> PartialEq for MergeHead<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Ord> Eq for MergeHead<T> {}
+
+/// The heads of the lists that are merged in [LinkedList::merge_k_sorted], which own the unmerged nodes.
+/// The nodes are freed if a comparison panics
+struct MergeHeads<T>(BinaryHeap<MergeHead<T>>);
+
+impl<T> Drop for MergeHeads<T> {
+    fn drop(&mut self) {
+        for head in self.0.drain() {
+            let mut item = Some(head.node);
+            while let Some(node) = item {
+                // SAFETY: All pointers should always be valid and created from a box, the nodes are only
+                // owned by the heap
+                unsafe {
+                    item = node.as_ref().next;
+                    drop(Box::from_raw(node.as_ptr()));
+                }
             }
         }
     }
@@ -356,6 +1352,62 @@ impl<T> Node<T> {
         self.next = new_node;
     }
 
+    /// Push all values of the iterator after this node, keeping their order
+    ///
+    /// The new nodes are only linked into the list after the iterator is exhausted, so if it panics, the list
+    /// stays unchanged
+    pub fn push_iter_after<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        /// The new nodes that are not part of the list yet, freed if the iterator panics
+        struct Chain<T> {
+            first: Option<NonNull<Node<T>>>,
+            last: Option<NonNull<Node<T>>>,
+        }
+
+        impl<T> Drop for Chain<T> {
+            fn drop(&mut self) {
+                let mut item = self.first;
+                while let Some(node) = item {
+                    // SAFETY: the nodes of the chain were created from a box and are only owned by the chain
+                    unsafe {
+                        item = node.as_ref().next;
+                        drop(Box::from_raw(node.as_ptr()));
+                    }
+                }
+            }
+        }
+
+        let mut chain = Chain {
+            first: None,
+            last: None,
+        };
+        for element in iter {
+            let new_node = allocate_nonnull(Node {
+                value: element,
+                next: None,
+                prev: chain.last,
+            });
+            match chain.last {
+                // SAFETY: All pointers should always be valid and created from a box
+                Some(mut last) => unsafe { last.as_mut() }.next = Some(new_node),
+                None => chain.first = Some(new_node),
+            }
+            chain.last = Some(new_node);
+        }
+
+        if let (Some(mut first), Some(mut last)) = (chain.first.take(), chain.last.take()) {
+            let old_next = self.next;
+            // SAFETY: All pointers should always be valid and created from a box
+            unsafe {
+                first.as_mut().prev = NonNull::new(self as _);
+                last.as_mut().next = old_next;
+                if let Some(mut next) = old_next {
+                    next.as_mut().prev = Some(last);
+                }
+            }
+            self.next = Some(first);
+        }
+    }
+
     /// Push a value before this node
     pub fn push_before(&mut self, element: T) {
         let new_node = Some(allocate_nonnull(Node {
@@ -446,7 +1498,9 @@ impl<'a, T> Iterator for Iter<'a, T> {
 pub struct IntoIter<T>(Option<Box<Node<T>>>);
 
 impl<T> IntoIter<T> {
-    fn new(list: LinkedList<T>) -> Self {
+    fn new(mut list: LinkedList<T>) -> Self {
+        // the retained nodes are not needed by the iterator
+        list.free_pool();
         let iter = Self(list.start.as_ref().map(|nn| {
             // SAFETY: All pointers should always be valid, the list lives as long as its items
             unsafe { Box::from_raw(nn.as_ptr()) }
@@ -512,3 +1566,37 @@ impl<'a, T> Iterator for IterMut<'a, T> {
         }
     }
 }
+
+/// The iterator returned by [LinkedList::extract_if]
+pub struct ExtractIf<'a, T, F: FnMut(&mut T) -> bool> {
+    list: &'a mut LinkedList<T>,
+    current: Option<NonNull<Node<T>>>,
+    pred: F,
+}
+
+impl<'a, T, F: FnMut(&mut T) -> bool> Iterator for ExtractIf<'a, T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(mut node) = self.current {
+            // SAFETY: All pointers should always be valid, the list is borrowed mutably
+            let content = unsafe { node.as_mut() };
+            self.current = content.next;
+            if (self.pred)(&mut content.value) {
+                // relink the neighbours around the removed node
+                match content.prev {
+                    Some(mut prev) => unsafe { prev.as_mut() }.next = content.next,
+                    None => self.list.start = content.next,
+                }
+                match content.next {
+                    Some(mut next) => unsafe { next.as_mut() }.prev = content.prev,
+                    None => self.list.end = content.prev,
+                }
+                // SAFETY: the node was created from a box and is not referenced by the list anymore
+                let boxed = unsafe { Box::from_raw(node.as_ptr()) };
+                return Some(boxed.value);
+            }
+        }
+        None
+    }
+}
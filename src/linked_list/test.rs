@@ -1,8 +1,10 @@
 use super::*;
+use crate::linked_list;
+use std::cell::Cell;
 
 #[test]
 fn random_access() {
-    let list = create_list(&["nice", "test", "hallo"]);
+    let list = linked_list!["nice", "test", "hallo"];
     assert_eq!(list.get(0), Some(&"nice"));
     assert_eq!(list.get(1), Some(&"test"));
     assert_eq!(list.get(2), Some(&"hallo"));
@@ -23,7 +25,7 @@ fn push_start_end() {
 
 #[test]
 fn pop_back() {
-    let mut list = create_list(&["hi", "3", "5"]);
+    let mut list = linked_list!["hi", "3", "5"];
     assert_eq!(Some("5"), list.pop_back());
     assert_eq!(Some("3"), list.pop_back());
     assert_eq!(Some("hi"), list.pop_back());
@@ -32,7 +34,7 @@ fn pop_back() {
 
 #[test]
 fn pop_front() {
-    let mut list = create_list(&["hi", "3", "5"]);
+    let mut list = linked_list!["hi", "3", "5"];
     assert_eq!(Some("hi"), list.pop_front());
     assert_eq!(Some("3"), list.pop_front());
     assert_eq!(Some("5"), list.pop_front());
@@ -41,7 +43,7 @@ fn pop_front() {
 
 #[test]
 fn iter_simple() {
-    let list = create_list(&["nice", "test", "hallo"]);
+    let list = linked_list!["nice", "test", "hallo"];
     let mut iter = list.iter();
     assert_eq!(iter.next(), Some(&"nice"));
     assert_eq!(iter.next(), Some(&"test"));
@@ -52,7 +54,7 @@ fn iter_simple() {
 
 #[test]
 fn iterator() {
-    let list = create_list(&["nice", "test", "hallo"]);
+    let list = linked_list!["nice", "test", "hallo"];
     let vec = list.iter().collect::<Vec<_>>();
     assert_eq!(vec[0], &"nice");
     assert_eq!(vec[1], &"test");
@@ -62,7 +64,7 @@ fn iterator() {
 
 #[test]
 fn into_iterator() {
-    let list = create_list(&["nice", "test", "hallo"]);
+    let list = linked_list!["nice", "test", "hallo"];
     let vec = list.into_iter().collect::<Vec<_>>();
     assert_eq!(vec[0], "nice");
     assert_eq!(vec[1], "test");
@@ -72,12 +74,12 @@ fn into_iterator() {
 
 #[test]
 fn iter_mut() {
-    let mut list = create_list(&[1, 2, 3]);
+    let mut list = linked_list![1, 2, 3];
     let iter = list.iter_mut();
     iter.for_each(|item| {
         *item *= 2;
     });
-    assert_eq!(list, create_list(&[2, 4, 6]));
+    assert_eq!(list, linked_list![2, 4, 6]);
 }
 
 #[test]
@@ -100,7 +102,7 @@ fn node_operations() {
         assert_eq!(*node.get(), 2);
         node.push_after(4);
         let next = node.next_mut().unwrap();
-        assert!(matches!(next.next(), None));
+        assert!(next.next().is_none());
         next.push_before(3)
     }
     let vec = list.iter().cloned().collect::<Vec<_>>();
@@ -123,18 +125,18 @@ fn node_values() {
 
 #[test]
 fn list_len() {
-    let list = create_list(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    let list = linked_list![1, 2, 3, 4, 5, 6, 7, 8, 9];
     assert_eq!(list.len(), 9);
 }
 
 #[test]
 fn std_traits() {
-    let mut list1 = create_list(&[1, 5, 732, 533]);
-    let list2 = create_list(&[1, 5, 732, 533]);
+    let mut list1 = linked_list![1, 5, 732, 533];
+    let list2 = linked_list![1, 5, 732, 533];
     assert_eq!(list1, list2);
 
     list1.extend([99, 100].iter().cloned());
-    assert_eq!(list1, create_list(&[1, 5, 732, 533, 99, 100]));
+    assert_eq!(list1, linked_list![1, 5, 732, 533, 99, 100]);
 
     let vec1 = vec![1, 5, 732, 533, 99, 100];
     let list_from_vec = vec1.into_iter().collect::<LinkedList<_>>();
@@ -143,11 +145,751 @@ fn std_traits() {
 
 #[test]
 fn into_iter_not_consumed() {
-    let list = create_list(&[1, 2, 4, 6, 7, 4, 5, 7, 57, 5]);
+    let list = linked_list![1, 2, 4, 6, 7, 4, 5, 7, 57, 5];
     list.into_iter();
 }
 
-/// Creates an owned list from a slice, not efficient at all but easy to use
-fn create_list<T: Clone>(iter: &[T]) -> LinkedList<T> {
-    iter.into_iter().cloned().collect()
+#[test]
+fn extract_if() {
+    let mut list = linked_list![1, 2, 3, 4, 5, 6];
+    let evens = list.extract_if(|item| *item % 2 == 0).collect::<Vec<_>>();
+    assert_eq!(evens, vec![2, 4, 6]);
+    assert_eq!(list, linked_list![1, 3, 5]);
+
+    let mut list = linked_list![1, 2, 3, 4, 5, 6];
+    {
+        let mut iter = list.extract_if(|item| *item % 2 == 0);
+        assert_eq!(iter.next(), Some(2));
+    }
+    assert_eq!(list, linked_list![1, 3, 4, 5, 6]);
+}
+
+#[test]
+fn chunks() {
+    let list = linked_list![1, 2, 3, 4, 5, 6, 7];
+    let chunks = list
+        .chunks(3)
+        .map(|chunk| chunk.into_iter().copied().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(chunks, vec![vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+    assert_eq!(LinkedList::<i32>::new().chunks(3).count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn zero_chunks() {
+    let _ = linked_list![1, 2].chunks(0);
+}
+
+#[test]
+fn split_into_chunks() {
+    let list = linked_list![1, 2, 3, 4, 5, 6, 7];
+    let chunks = list.split_into_chunks(3);
+    assert_eq!(
+        chunks,
+        vec![
+            linked_list![1, 2, 3],
+            linked_list![4, 5, 6],
+            linked_list![7]
+        ]
+    );
+    assert_eq!(chunks[2].get_head(), chunks[2].get_tail());
+
+    assert!(LinkedList::<i32>::new().split_into_chunks(2).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn split_into_zero_chunks() {
+    linked_list![1, 2, 3].split_into_chunks(0);
+}
+
+#[test]
+fn list_macro() {
+    let list = crate::linked_list![1, 2, 3];
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    let list = crate::linked_list!["a", "b",];
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec!["a", "b"]);
+    let empty: LinkedList<i32> = crate::linked_list![];
+    assert_eq!(empty, LinkedList::new());
+}
+
+#[test]
+fn first_last_mut() {
+    let mut list = linked_list![1, 2, 3];
+    *list.first_mut().unwrap() = 10;
+    *list.last_mut().unwrap() += 27;
+    assert_eq!(list.iter().cloned().collect::<Vec<_>>(), vec![10, 2, 30]);
+
+    let mut empty = LinkedList::<i32>::new();
+    assert_eq!(empty.first_mut(), None);
+    assert_eq!(empty.last_mut(), None);
+}
+
+#[test]
+fn from_iter_rev() {
+    let list = LinkedList::from_iter_rev(vec![1, 2, 3]);
+    assert_eq!(list, linked_list![3, 2, 1]);
+    assert_eq!(list.get_tail(), Some(&1));
+}
+
+#[test]
+fn is_sorted() {
+    assert!(linked_list![1, 2, 2, 5, 8].is_sorted());
+    assert!(!linked_list![1, 3, 2, 5].is_sorted());
+    assert!(LinkedList::<i32>::new().is_sorted());
+    assert!(linked_list![1].is_sorted());
+    assert!(linked_list![5, 3, 1].is_sorted_by(|a, b| a >= b));
+
+    let mut comparisons = 0;
+    let sorted = linked_list![2, 1, 3, 4].is_sorted_by(|a, b| {
+        comparisons += 1;
+        a <= b
+    });
+    assert!(!sorted);
+    assert_eq!(comparisons, 1);
+}
+
+#[test]
+fn rotate_to_front() {
+    let mut list = linked_list![1, 2, 3, 4, 5];
+    let third = list.get(2).unwrap() as *const i32;
+    list.rotate_to_front(2);
+    assert_eq!(list, linked_list![3, 4, 5, 1, 2]);
+    // the values are not moved
+    assert_eq!(list.get_head().unwrap() as *const i32, third);
+    // the back links are fixed up as well
+    assert_links(&list, &[3, 4, 5, 1, 2]);
+
+    list.rotate_to_front(0);
+    assert_eq!(list, linked_list![3, 4, 5, 1, 2]);
+    list.rotate_to_front(4);
+    assert_eq!(list, linked_list![2, 3, 4, 5, 1]);
+    assert_eq!(list.get_tail(), Some(&1));
+
+    let mut list = linked_list![1];
+    list.rotate_to_front(0);
+    assert_eq!(list, linked_list![1]);
+}
+
+#[test]
+fn move_to_front() {
+    let mut list = linked_list![1, 2, 3, 4, 5];
+    list.move_to_front(3);
+    assert_eq!(list, linked_list![4, 1, 2, 3, 5]);
+    list.move_to_front(0);
+    assert_eq!(list, linked_list![4, 1, 2, 3, 5]);
+    list.move_to_front(4);
+    assert_eq!(list, linked_list![5, 4, 1, 2, 3]);
+    assert_eq!(list.get_tail(), Some(&3));
+
+    assert_links(&list, &[5, 4, 1, 2, 3]);
+}
+
+#[test]
+fn rotate_wrapping() {
+    let mut list = linked_list![1, 2, 3, 4, 5];
+    list.rotate_left_wrapping(7);
+    assert_eq!(list, linked_list![3, 4, 5, 1, 2]);
+    list.rotate_right_wrapping(7);
+    assert_eq!(list, linked_list![1, 2, 3, 4, 5]);
+    list.rotate_right_wrapping(1);
+    assert_eq!(list, linked_list![5, 1, 2, 3, 4]);
+    list.rotate_left_wrapping(10);
+    assert_eq!(list, linked_list![5, 1, 2, 3, 4]);
+
+    let mut empty = LinkedList::<i32>::new();
+    empty.rotate_left_wrapping(3);
+    empty.rotate_right_wrapping(3);
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn rotate_to_front_out_of_bounds() {
+    linked_list![1, 2, 3].rotate_to_front(3);
+}
+
+#[test]
+fn pop_front_if() {
+    let mut list = linked_list![1, 2, 3, 4];
+    let mut drained = Vec::new();
+    while let Some(value) = list.pop_front_if(|value| *value < 3) {
+        drained.push(value);
+    }
+    assert_eq!(drained, vec![1, 2]);
+    assert_eq!(list, linked_list![3, 4]);
+
+    assert_eq!(list.pop_front_if(|value| *value == 4), None);
+    assert_eq!(list, linked_list![3, 4]);
+
+    let mut empty = LinkedList::<i32>::new();
+    assert_eq!(empty.pop_front_if(|_| true), None);
+}
+
+#[test]
+fn push_iter_after() {
+    let mut list = linked_list![1, 2];
+    list.front_node_mut()
+        .unwrap()
+        .push_iter_after(vec![10, 11, 12]);
+    assert_eq!(list, linked_list![1, 10, 11, 12, 2]);
+    assert_eq!(list.back_node().unwrap().previous().unwrap().get(), &12);
+
+    list.front_node_mut().unwrap().push_iter_after(Vec::new());
+    assert_eq!(list, linked_list![1, 10, 11, 12, 2]);
+}
+
+#[test]
+fn push_iter_after_panicking_iter() {
+    let mut list = linked_list![String::from("1"), String::from("2")];
+    let iter = (10..).map(|i| {
+        if i == 12 {
+            panic!("the iterator panics");
+        }
+        i.to_string()
+    });
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        list.front_node_mut().unwrap().push_iter_after(iter)
+    }));
+    assert!(result.is_err());
+    assert_eq!(list, linked_list![String::from("1"), String::from("2")]);
+    assert_eq!(list.back_node().unwrap().previous().unwrap().get(), "1");
+
+    list.push_back(String::from("3"));
+    assert_eq!(list.pop_back().as_deref(), Some("3"));
+    assert_eq!(list.pop_back().as_deref(), Some("2"));
+    assert_eq!(list, linked_list![String::from("1")]);
+}
+
+#[test]
+fn contains_node() {
+    let a = linked_list![1, 2, 3];
+    let b = linked_list![1, 2, 3];
+    let node = a.get_node(1).unwrap();
+    assert!(a.contains_node(node));
+    assert!(!b.contains_node(node));
+    assert!(!LinkedList::new().contains_node(node));
+}
+
+#[test]
+fn try_push() {
+    let mut list = linked_list![2];
+    assert!(list.try_push_back(3).is_ok());
+    assert!(list.try_push_front(1).is_ok());
+    assert_eq!(list, linked_list![1, 2, 3]);
+
+    let mut list = LinkedList::<String>::new();
+    for i in 0..20 {
+        list.try_push_back(i.to_string()).unwrap();
+        list.try_push_front(i.to_string()).unwrap();
+    }
+    assert_eq!(list.len(), 40);
+}
+
+#[test]
+fn reverse_range() {
+    let mut list = linked_list![1, 2, 3, 4, 5];
+    list.reverse_range(1, 4);
+    assert_eq!(list, linked_list![1, 4, 3, 2, 5]);
+    list.reverse_range(0, 2);
+    assert_eq!(list, linked_list![4, 1, 3, 2, 5]);
+    list.reverse_range(3, 5);
+    assert_eq!(list, linked_list![4, 1, 3, 5, 2]);
+    list.reverse_range(0, 5);
+    assert_eq!(list, linked_list![2, 5, 3, 1, 4]);
+    list.reverse_range(2, 2);
+    list.reverse_range(2, 3);
+    assert_eq!(list, linked_list![2, 5, 3, 1, 4]);
+
+    assert_eq!(list.get_head(), Some(&2));
+    assert_eq!(list.get_tail(), Some(&4));
+    assert_links(&list, &[2, 5, 3, 1, 4]);
+}
+
+#[test]
+#[should_panic]
+fn reverse_range_out_of_bounds() {
+    linked_list![1, 2, 3].reverse_range(1, 4);
+}
+
+#[test]
+fn zip() {
+    struct DropCounter<'a>(i32, &'a Cell<usize>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.1.set(self.1.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    let mut numbers = LinkedList::new();
+    for i in 1..=3 {
+        numbers.push_back(DropCounter(i, &drops));
+    }
+    let zipped = numbers.zip(linked_list!['a', 'b']);
+    assert_eq!(drops.get(), 1);
+    assert_eq!(
+        zipped
+            .iter()
+            .map(|(number, char)| (number.0, *char))
+            .collect::<Vec<_>>(),
+        vec![(1, 'a'), (2, 'b')]
+    );
+    drop(zipped);
+    assert_eq!(drops.get(), 3);
+
+    let zipped = linked_list![1].zip(linked_list!["a", "b"]);
+    assert_eq!(zipped, linked_list![(1, "a")]);
+}
+
+#[test]
+fn interleave() {
+    let mut list = linked_list![1, 3, 5];
+    list.interleave(linked_list![2, 4, 6, 7, 8]);
+    assert_eq!(list, linked_list![1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(list.get_tail(), Some(&8));
+
+    let mut list = linked_list![1, 3, 5, 6];
+    list.interleave(linked_list![2, 4]);
+    assert_eq!(list, linked_list![1, 2, 3, 4, 5, 6]);
+    assert_links(&list, &[1, 2, 3, 4, 5, 6]);
+
+    let mut list = LinkedList::new();
+    list.interleave(linked_list![1, 2]);
+    assert_eq!(list, linked_list![1, 2]);
+    list.interleave(LinkedList::new());
+    assert_eq!(list, linked_list![1, 2]);
+}
+
+#[test]
+fn clear_retaining_allocations() {
+    // this test is mostly useful under Miri, which detects leaked or double dropped values
+    let mut list = LinkedList::new();
+    for round in 0..3 {
+        for i in 0..5 {
+            list.push_back(format!("{}-{}", round, i));
+        }
+        list.push_front(String::from("front"));
+        let last = list.back_node().unwrap() as *const Node<String>;
+        assert_eq!(list.len(), 6);
+        list.clear_retaining_allocations();
+        assert!(list.is_empty());
+        assert_eq!(list.get_head(), None);
+        assert_eq!(list.get_tail(), None);
+
+        // the node that was cleared last is reused first
+        list.push_back(String::from("reused"));
+        assert_eq!(list.front_node().unwrap() as *const Node<String>, last);
+        list.clear_retaining_allocations();
+    }
+    list.push_back(String::from("a"));
+    assert_eq!(list.into_iter().collect::<Vec<_>>(), vec!["a"]);
+}
+
+#[test]
+fn ptr_eq() {
+    let list = linked_list![1, 2, 3];
+    let clone = list.clone();
+    assert!(list.ptr_eq(&list));
+    assert!(!list.ptr_eq(&clone));
+    assert_eq!(list, clone);
+
+    let empty = LinkedList::<i32>::new();
+    assert!(empty.ptr_eq(&empty));
+    assert!(!empty.ptr_eq(&LinkedList::<i32>::new()));
+    assert!(!empty.ptr_eq(&list));
+}
+
+#[test]
+fn push_back_counting_inversions() {
+    let mut list = LinkedList::new();
+    let inversions = [3, 1, 2]
+        .iter()
+        .map(|&value| list.push_back_counting_inversions(value))
+        .collect::<Vec<_>>();
+    assert_eq!(inversions, vec![0, 1, 1]);
+    assert_eq!(list, linked_list![3, 1, 2]);
+}
+
+#[test]
+fn middle() {
+    assert_eq!(linked_list![1, 2, 3, 4, 5].middle(), Some(&3));
+    assert_eq!(linked_list![1, 2, 3, 4].middle(), Some(&2));
+    assert_eq!(linked_list![1].middle(), Some(&1));
+    assert_eq!(LinkedList::<i32>::new().middle(), None);
+}
+
+#[test]
+fn has_cycle() {
+    assert!(!LinkedList::<i32>::new().has_cycle());
+    assert!(!linked_list![1].has_cycle());
+    assert!(!linked_list![1, 2, 3, 4, 5].has_cycle());
+
+    let list = linked_list![1, 2, 3, 4, 5];
+    let mut end = list.end.unwrap();
+    // link the last node back to the second one
+    unsafe { end.as_mut().next = list.start.unwrap().as_ref().next };
+    assert!(list.has_cycle());
+    // break the cycle again so the list can be dropped
+    unsafe { end.as_mut().next = None };
+    assert!(!list.has_cycle());
+
+    let single = linked_list![1];
+    let mut node = single.start.unwrap();
+    unsafe { node.as_mut().next = Some(node) };
+    assert!(single.has_cycle());
+    unsafe { node.as_mut().next = None };
+}
+
+#[test]
+fn dedup_iter() {
+    let list = linked_list![1, 1, 2, 3, 3, 1];
+    assert_eq!(list.dedup_iter().collect::<Vec<_>>(), vec![&1, &2, &3, &1]);
+    assert_eq!(list, linked_list![1, 1, 2, 3, 3, 1]);
+    assert_eq!(LinkedList::<i32>::new().dedup_iter().next(), None);
+}
+
+#[test]
+fn longest_increasing_run() {
+    assert_eq!(linked_list![1, 2, 3, 1, 2].longest_increasing_run(), 3);
+    assert_eq!(linked_list![5, 4, 3].longest_increasing_run(), 1);
+    assert_eq!(linked_list![1, 1, 2, 3, 4].longest_increasing_run(), 4);
+    assert_eq!(linked_list![1].longest_increasing_run(), 1);
+    assert_eq!(LinkedList::<i32>::new().longest_increasing_run(), 0);
+}
+
+#[test]
+fn count_distinct_sorted() {
+    assert_eq!(linked_list![1, 2, 2, 3, 1].count_distinct_sorted(), 3);
+    assert_eq!(linked_list![1, 1, 1].count_distinct_sorted(), 1);
+    assert_eq!(LinkedList::<i32>::new().count_distinct_sorted(), 0);
+}
+
+#[test]
+fn reverse_in_groups() {
+    let mut list = linked_list![1, 2, 3, 4, 5];
+    list.reverse_in_groups(2, false);
+    assert_eq!(list, linked_list![2, 1, 4, 3, 5]);
+    assert_eq!(list.back_node().unwrap().previous().unwrap().get(), &3);
+
+    let mut list = linked_list![1, 2, 3, 4, 5];
+    list.reverse_in_groups(3, true);
+    assert_eq!(list, linked_list![3, 2, 1, 5, 4]);
+    assert_links(&list, &[3, 2, 1, 5, 4]);
+
+    let mut list = linked_list![1, 2, 3, 4];
+    list.reverse_in_groups(2, false);
+    assert_eq!(list, linked_list![2, 1, 4, 3]);
+    list.reverse_in_groups(1, true);
+    assert_eq!(list, linked_list![2, 1, 4, 3]);
+    list.reverse_in_groups(0, true);
+    assert_eq!(list, linked_list![2, 1, 4, 3]);
+    list.reverse_in_groups(10, false);
+    assert_eq!(list, linked_list![2, 1, 4, 3]);
+    list.reverse_in_groups(10, true);
+    assert_eq!(list, linked_list![3, 4, 1, 2]);
+}
+
+#[test]
+fn scan_into_vec() {
+    let list = linked_list![1, 2, 3, 4];
+    assert_eq!(
+        list.scan_into_vec(0, |sum, item| sum + item),
+        vec![1, 3, 6, 10]
+    );
+    assert_eq!(
+        list.scan_into_vec(String::new(), |acc, item| format!("{}{}", acc, item)),
+        vec!["1", "12", "123", "1234"]
+    );
+    assert!(LinkedList::<i32>::new()
+        .scan_into_vec(0, |sum, item| sum + item)
+        .is_empty());
+}
+
+#[test]
+fn merge_k_sorted() {
+    let merged = LinkedList::merge_k_sorted(vec![
+        linked_list![1, 4, 7],
+        linked_list![2, 5],
+        linked_list![3, 6, 8, 9],
+    ]);
+    assert_eq!(merged, linked_list![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(merged.back_node().unwrap().previous().unwrap().get(), &8);
+
+    // equal elements keep the order of the lists
+    let merged = LinkedList::merge_k_sorted(
+        vec![
+            linked_list![(1, 'a'), (2, 'a')],
+            LinkedList::new(),
+            linked_list![(1, 'b'), (3, 'b')],
+        ]
+        .into_iter()
+        .map(|list| {
+            list.into_iter()
+                .map(|(value, list)| Key(value, list))
+                .collect()
+        }),
+    );
+    assert_eq!(
+        merged.iter().map(|key| (key.0, key.1)).collect::<Vec<_>>(),
+        vec![(1, 'a'), (1, 'b'), (2, 'a'), (3, 'b')]
+    );
+
+    assert!(LinkedList::<i32>::merge_k_sorted(Vec::new()).is_empty());
+}
+
+/// Only compares the first value
+#[derive(Debug)]
+struct Key(i32, char);
+
+impl PartialEq for Key {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for Key {}
+
+impl PartialOrd for Key {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Key {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+#[test]
+fn merge_k_sorted_panicking_cmp() {
+    // this test is mostly useful under Miri, which detects leaked or double dropped values
+    #[derive(Debug, PartialEq, Eq)]
+    struct PanicOnCmp(String);
+
+    impl PartialOrd for PanicOnCmp {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for PanicOnCmp {
+        fn cmp(&self, other: &Self) -> Ordering {
+            if self.0 == "panic" || other.0 == "panic" {
+                panic!("comparing {:?} and {:?}", self.0, other.0);
+            }
+            self.0.cmp(&other.0)
+        }
+    }
+
+    let lists = vec![["a", "d", "panic"], ["b", "e", "f"], ["c", "g", "h"]]
+        .into_iter()
+        .map(|values| {
+            values
+                .iter()
+                .map(|value| PanicOnCmp(value.to_string()))
+                .collect::<LinkedList<_>>()
+        })
+        .collect::<Vec<_>>();
+    let result = std::panic::catch_unwind(|| LinkedList::merge_k_sorted(lists));
+    assert!(result.is_err());
+}
+
+#[test]
+fn remove_nth_from_end() {
+    let mut list = linked_list![1, 2, 3, 4, 5];
+    assert_eq!(list.remove_nth_from_end(2), Some(4));
+    assert_eq!(list, linked_list![1, 2, 3, 5]);
+    assert_eq!(list.remove_nth_from_end(1), Some(5));
+    assert_eq!(list.get_tail(), Some(&3));
+    assert_eq!(list.remove_nth_from_end(3), Some(1));
+    assert_eq!(list.get_head(), Some(&2));
+    assert_eq!(list, linked_list![2, 3]);
+
+    assert_eq!(list.remove_nth_from_end(3), None);
+    assert_eq!(list.remove_nth_from_end(0), None);
+    assert_eq!(list, linked_list![2, 3]);
+
+    let mut single = linked_list![1];
+    assert_eq!(single.remove_nth_from_end(1), Some(1));
+    assert!(single.is_empty());
+    assert_eq!(single.remove_nth_from_end(1), None);
+}
+
+#[test]
+fn is_palindrome() {
+    assert!(linked_list![1, 2, 3, 2, 1].is_palindrome());
+    assert!(linked_list![1, 2, 2, 1].is_palindrome());
+    assert!(!linked_list![1, 2, 3].is_palindrome());
+    assert!(!linked_list![1, 2, 1, 1].is_palindrome());
+    assert!(linked_list![1].is_palindrome());
+    assert!(LinkedList::<i32>::new().is_palindrome());
+}
+
+#[test]
+fn run_length_encode() {
+    let encoded = linked_list![1, 1, 1, 2, 3, 3].run_length_encode();
+    assert_eq!(encoded, linked_list![(1, 3), (2, 1), (3, 2)]);
+    let encoded = linked_list![1, 2, 1].run_length_encode();
+    assert_eq!(encoded, linked_list![(1, 1), (2, 1), (1, 1)]);
+    assert!(LinkedList::<i32>::new().run_length_encode().is_empty());
+}
+
+#[test]
+fn run_length_decode() {
+    let decoded = linked_list![(1, 3), (2, 1), (3, 2)].run_length_decode();
+    assert_eq!(decoded, linked_list![1, 1, 1, 2, 3, 3]);
+    assert!(linked_list![(1, 0)].run_length_decode().is_empty());
+
+    let list = linked_list![1, 2, 2, 1, 1, 1];
+    assert_eq!(list.clone().run_length_encode().run_length_decode(), list);
+}
+
+#[test]
+fn range() {
+    let list = LinkedList::range(1, 5);
+    assert_eq!(list, linked_list![1, 2, 3, 4]);
+    assert_eq!(list.get_head(), Some(&1));
+    assert_eq!(list.get_tail(), Some(&4));
+    let back = list.back_node().unwrap();
+    assert_eq!(back.previous().unwrap().previous().unwrap().get(), &2);
+    assert_eq!(LinkedList::range(3, 4), linked_list![3]);
+    assert!(LinkedList::range(5, 5).is_empty());
+    assert!(LinkedList::range(5, 1).is_empty());
+    assert_eq!(LinkedList::from_range(-2..1), linked_list![-2, -1, 0]);
+    assert_eq!(
+        LinkedList::from_range(vec!['a', 'b']),
+        linked_list!['a', 'b']
+    );
+}
+
+#[test]
+fn splice_out() {
+    let mut list = linked_list![1, 2, 3, 4, 5];
+    let removed = list.splice_out(1, 4);
+    assert_eq!(list, linked_list![1, 5]);
+    assert_eq!(removed, linked_list![2, 3, 4]);
+    assert_eq!(list.back_node().unwrap().previous().unwrap().get(), &1);
+    assert!(removed.front_node().unwrap().previous().is_none());
+    assert_eq!(removed.get_tail(), Some(&4));
+
+    let mut list = linked_list![1, 2, 3];
+    assert_eq!(list.splice_out(0, 1), linked_list![1]);
+    assert_eq!(list.get_head(), Some(&2));
+    assert_eq!(list.splice_out(1, 2), linked_list![3]);
+    assert_eq!(list.get_tail(), Some(&2));
+    assert!(list.splice_out(1, 1).is_empty());
+    assert!(list.splice_out(0, 0).is_empty());
+    assert_eq!(list.splice_out(0, 1), linked_list![2]);
+    assert!(list.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn splice_out_out_of_bounds() {
+    linked_list![1, 2, 3].splice_out(1, 4);
+}
+
+#[test]
+fn splice_in() {
+    let mut list = linked_list![1, 2, 3];
+    list.splice_in(1, linked_list![10, 11]);
+    list.splice_in(2, LinkedList::new());
+    assert_eq!(list, linked_list![1, 10, 11, 2, 3]);
+    assert_eq!(list.get_node(3).unwrap().previous().unwrap().get(), &11);
+
+    list.splice_in(0, linked_list![0]);
+    list.splice_in(6, linked_list![4, 5]);
+    assert_eq!(list, linked_list![0, 1, 10, 11, 2, 3, 4, 5]);
+    assert_eq!(list.back_node().unwrap().previous().unwrap().get(), &4);
+
+    let mut empty = LinkedList::new();
+    empty.splice_in(0, linked_list![1, 2]);
+    assert_eq!(empty, linked_list![1, 2]);
+
+    let mut list = linked_list![1, 2, 3, 4, 5];
+    let removed = list.splice_out(1, 4);
+    list.splice_in(1, removed);
+    assert_eq!(list, linked_list![1, 2, 3, 4, 5]);
+}
+
+#[test]
+#[should_panic]
+fn splice_in_out_of_bounds() {
+    linked_list![1, 2, 3].splice_in(4, linked_list![1]);
+}
+
+#[test]
+fn median() {
+    let list = linked_list![3, 1, 2, 5, 4];
+    assert_eq!(list.median(), Some(&3));
+    assert_eq!(list, linked_list![3, 1, 2, 5, 4]);
+    assert_eq!(linked_list![4, 1, 3, 2].median(), Some(&2));
+    assert_eq!(linked_list![1].median(), Some(&1));
+    assert_eq!(LinkedList::<i32>::new().median(), None);
+}
+
+#[test]
+fn deltas() {
+    let list = linked_list![10, 13, 13, 20];
+    let deltas = list.deltas();
+    assert_eq!(deltas, linked_list![10, 3, 0, 7]);
+    assert_eq!(
+        deltas.scan_into_vec(0, |sum, delta| sum + delta),
+        vec![10, 13, 13, 20]
+    );
+    assert_eq!(linked_list![5, 2].deltas(), linked_list![5, -3]);
+    assert!(LinkedList::<i32>::new().deltas().is_empty());
+}
+
+#[test]
+fn from_deltas() {
+    let deltas = linked_list![10, 3, 0, 7];
+    assert_eq!(deltas.from_deltas(), linked_list![10, 13, 13, 20]);
+    let list = linked_list![4, -2, 8, 8, 1];
+    assert_eq!(list.deltas().from_deltas(), list);
+    assert!(LinkedList::<i32>::new().from_deltas().is_empty());
+}
+
+#[test]
+fn flatten() {
+    let nested = linked_list![
+        linked_list![1, 2],
+        LinkedList::new(),
+        linked_list![3],
+        linked_list![4, 5],
+    ];
+    let flat = nested.flatten();
+    assert_eq!(flat, linked_list![1, 2, 3, 4, 5]);
+    assert_eq!(flat.get_node(2).unwrap().previous().unwrap().get(), &2);
+    assert_eq!(flat.back_node().unwrap().previous().unwrap().get(), &4);
+
+    let only_empty = linked_list![LinkedList::<i32>::new(), LinkedList::new()];
+    assert!(only_empty.flatten().is_empty());
+}
+
+#[test]
+fn try_into_array() {
+    let array: [i32; 3] = <[i32; 3]>::try_from(linked_list![1, 2, 3]).unwrap();
+    assert_eq!(array, [1, 2, 3]);
+
+    let list = <[i32; 4]>::try_from(linked_list![1, 2, 3]).unwrap_err();
+    assert_eq!(list, linked_list![1, 2, 3]);
+}
+
+/// Asserts that the list contains the expected values, following the links from the front and from the back
+fn assert_links<T: Debug + PartialEq + Clone>(list: &LinkedList<T>, expected: &[T]) {
+    assert_eq!(list.iter().cloned().collect::<Vec<_>>(), expected);
+
+    let mut node = list.back_node();
+    let mut backwards = Vec::new();
+    while let Some(current) = node {
+        backwards.push(current.get().clone());
+        node = current.previous();
+    }
+    backwards.reverse();
+    assert_eq!(backwards, expected);
 }
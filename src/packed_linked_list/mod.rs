@@ -1,12 +1,18 @@
 #[cfg(test)]
 mod test;
 
+use crate::alloc::try_allocate_nonnull;
+use crate::linked_list::LinkedList;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, TryReserveError};
+use std::convert::TryFrom;
 use std::fmt::{Debug, Formatter};
-use std::hash::Hasher;
+use std::hash::{Hash, Hasher};
 use std::iter::FromIterator;
 use std::marker::PhantomData;
 use std::mem;
 use std::mem::MaybeUninit;
+use std::ops::RangeBounds;
 use std::option::Option::Some;
 use std::ptr::NonNull;
 
@@ -15,6 +21,26 @@ fn allocate_nonnull<T>(element: T) -> NonNull<T> {
     unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(element))) }
 }
 
+/// Creates a [PackedLinkedList] with the given `COUNT` containing the arguments, like `vec![]`
+///
+/// ```
+/// # use datastructures::packed_linked_list;
+/// #
+/// let list = packed_linked_list![16; 1, 2, 3];
+/// assert_eq!(list.len(), 3);
+/// ```
+#[macro_export]
+macro_rules! packed_linked_list {
+    ($count:expr $(;)?) => {
+        $crate::packed_linked_list::PackedLinkedList::<_, $count>::new()
+    };
+    ($count:expr; $($item:expr),+ $(,)?) => {{
+        let mut list = $crate::packed_linked_list::PackedLinkedList::<_, $count>::new();
+        $(list.push_back($item);)+
+        list
+    }};
+}
+
 ///
 /// A more efficient implementation of a linked list
 ///
@@ -25,21 +51,56 @@ fn allocate_nonnull<T>(element: T) -> NonNull<T> {
 ///
 /// Another way to optimize a linked list is by having a `Vec` of nodes that each have relative references,
 /// but this implementation does not implement this.
+///
+/// A list created using [PackedLinkedList::with_node_pool] keeps emptied nodes around and reuses them for
+/// new nodes, which avoids allocator calls when elements are pushed and popped repeatedly.
 #[derive(Eq)]
 pub struct PackedLinkedList<T, const COUNT: usize> {
     first: Option<NonNull<Node<T, COUNT>>>,
     last: Option<NonNull<Node<T, COUNT>>>,
     len: usize,
+    /// Unused nodes that can be reused, linked through their `next` pointer
+    pool: Option<NonNull<Node<T, COUNT>>>,
+    use_pool: bool,
+    insert_policy: InsertPolicy,
+    #[cfg(feature = "metrics")]
+    metrics: Metrics,
     _maker: PhantomData<T>,
 }
 
+/// Decides how space is made when a value is inserted into the middle of a full node using a [CursorMut]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InsertPolicy {
+    /// Moves all values after the insertion point into a new node. This copies the least values right now, but
+    /// leaves a full node behind if the values are inserted at the end of the node
+    #[default]
+    AlwaysSplit,
+    /// Moves the last value of the node to the front of the next node if that one is not full, otherwise
+    /// behaves like [InsertPolicy::AlwaysSplit]. This avoids allocating new nodes, which is good for iterating
+    PreferNext,
+    /// Moves half of the values into a new node, so that both nodes have space for future inserts, which is good
+    /// for repeated inserts at the same place
+    BalancedSplit,
+}
+
+/// Counts the allocator calls of a list, only available with the `metrics` feature
+#[cfg(feature = "metrics")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Metrics {
+    allocations: usize,
+    deallocations: usize,
+}
+
 impl<T, const COUNT: usize> Drop for PackedLinkedList<T, COUNT> {
     fn drop(&mut self) {
         let mut item = self.first;
         while let Some(node) = item {
-            let boxed = unsafe { Box::from_raw(node.as_ptr()) };
+            let mut boxed = unsafe { Box::from_raw(node.as_ptr()) };
+            // SAFETY: the values in the node are initialized and nobody else can access them anymore
+            unsafe { std::ptr::drop_in_place(boxed.as_mut_slice()) };
             item = boxed.next;
         }
+        self.clear_node_pool();
     }
 }
 
@@ -50,10 +111,98 @@ impl<T, const COUNT: usize> PackedLinkedList<T, COUNT> {
             first: None,
             last: None,
             len: 0,
+            pool: None,
+            use_pool: false,
+            insert_policy: InsertPolicy::AlwaysSplit,
+            #[cfg(feature = "metrics")]
+            metrics: Metrics::default(),
             _maker: PhantomData,
         }
     }
 
+    /// Constructs a PackedLinkedList from the values of the vec. The values are moved into the nodes in blocks
+    /// of `COUNT`, so every node except the last one is full
+    pub fn from_vec(mut vec: Vec<T>) -> Self {
+        let mut list = Self::new();
+        let len = vec.len();
+        // SAFETY: the values are moved out below, the vec must only free its buffer afterwards
+        unsafe { vec.set_len(0) };
+        let values = vec.as_ptr();
+
+        let mut moved = 0;
+        while moved < len {
+            let amount = (len - moved).min(COUNT);
+            list.insert_node_end();
+            // SAFETY: the new node is empty and has space for `COUNT` values,
+            // and every value of the vec is moved out exactly once
+            unsafe {
+                let node = list.last.unwrap().as_mut();
+                std::ptr::copy_nonoverlapping(values.add(moved), node.slot_mut(0), amount);
+                node.size = amount;
+            }
+            list.len += amount;
+            moved += amount;
+        }
+        list
+    }
+
+    /// Constructs an empty PackedLinkedList that keeps the allocations of emptied nodes for later reuse
+    ///
+    /// The pooled nodes are only freed when the list is dropped or [PackedLinkedList::clear_node_pool] is called
+    pub fn with_node_pool() -> Self {
+        Self {
+            use_pool: true,
+            ..Self::new()
+        }
+    }
+
+    /// Frees all nodes that are kept for reuse
+    pub fn clear_node_pool(&mut self) {
+        while let Some(node) = self.pool {
+            // SAFETY: pooled nodes are always created from a box and don't contain any values
+            let boxed = unsafe { Box::from_raw(node.as_ptr()) };
+            self.pool = boxed.next;
+            self.record_deallocation();
+        }
+    }
+
+    /// The amount of nodes this list has allocated from the allocator, nodes reused from the node pool
+    /// are not counted
+    #[cfg(feature = "metrics")]
+    pub fn allocations(&self) -> usize {
+        self.metrics.allocations
+    }
+
+    /// The amount of nodes this list has returned to the allocator, nodes put into the node pool are not counted
+    #[cfg(feature = "metrics")]
+    pub fn deallocations(&self) -> usize {
+        self.metrics.deallocations
+    }
+
+    fn record_allocation(&mut self) {
+        #[cfg(feature = "metrics")]
+        {
+            self.metrics.allocations += 1;
+        }
+    }
+
+    fn record_deallocation(&mut self) {
+        #[cfg(feature = "metrics")]
+        {
+            self.metrics.deallocations += 1;
+        }
+    }
+
+    /// The policy used by [CursorMut::insert_after] when a value is inserted into the middle of a full node
+    pub fn insert_policy(&self) -> InsertPolicy {
+        self.insert_policy
+    }
+
+    /// Sets the policy used by [CursorMut::insert_after] when a value is inserted into the middle of a full node
+    pub fn set_insert_policy(&mut self, policy: InsertPolicy) {
+        self.insert_policy = policy;
+    }
+
     /// The length of the list (O(1))
     pub fn len(&self) -> usize {
         self.len
@@ -64,6 +213,41 @@ impl<T, const COUNT: usize> PackedLinkedList<T, COUNT> {
         self.len() == 0
     }
 
+    /// Checks whether both lists share the same first node allocation, which is only the case if they are the same list
+    ///
+    /// Clones always get their own nodes, so this is false for a list and its clone. An empty list is only
+    /// `ptr_eq` to itself.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (self.first, other.first) {
+            (Some(a), Some(b)) => a == b,
+            _ => std::ptr::eq(self, other),
+        }
+    }
+
+    /// Compares the lists like `==`, but compares whole node slices at once if both lists have the same node
+    /// layout, which is a lot faster for simple values. Falls back to comparing element by element otherwise
+    pub fn eq_fast(&self, other: &Self) -> bool
+    where
+        T: Copy + Eq,
+    {
+        if self.len() != other.len() {
+            return false;
+        }
+        let mut nodes = (self.first, other.first);
+        while let (Some(a), Some(b)) = nodes {
+            // SAFETY: All pointers should always point to valid memory
+            let (a, b) = unsafe { (a.as_ref(), b.as_ref()) };
+            if a.size != b.size {
+                return self.iter().eq(other.iter());
+            }
+            if a.as_slice() != b.as_slice() {
+                return false;
+            }
+            nodes = (a.next, b.next);
+        }
+        true
+    }
+
     /// Pushes a new value to the front of the list
     pub fn push_front(&mut self, element: T) {
         // SAFETY: All pointers should always point to valid memory,
@@ -102,34 +286,52 @@ impl<T, const COUNT: usize> PackedLinkedList<T, COUNT> {
         }
     }
 
+    /// Pushes a new value to the front of the list
+    ///
+    /// Returns an error instead of aborting if a new node is needed and can't be allocated
+    pub fn try_push_front(&mut self, element: T) -> Result<(), TryReserveError> {
+        // SAFETY: All pointers should always point to valid memory,
+        unsafe {
+            if self.first.is_none_or(|node| node.as_ref().is_full()) {
+                let node = self.try_allocate_node(None, self.first)?;
+                self.link_node_start(node);
+            }
+            self.first.unwrap().as_mut().push_front(element);
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Pushes a new value to the back of the list
+    ///
+    /// Returns an error instead of aborting if a new node is needed and can't be allocated
+    pub fn try_push_back(&mut self, element: T) -> Result<(), TryReserveError> {
+        // SAFETY: All pointers should always point to valid memory,
+        unsafe {
+            if self.last.is_none_or(|node| node.as_ref().is_full()) {
+                let node = self.try_allocate_node(self.last, None)?;
+                self.link_node_end(node);
+            }
+            self.last.unwrap().as_mut().push_back(element);
+        }
+        self.len += 1;
+        Ok(())
+    }
+
     /// Pops the front element and returns it
+    ///
+    /// This is O(1), the values of the node are not moved down, the start of the node moves up instead
     pub fn pop_front(&mut self) -> Option<T> {
         let first = &mut self.first?;
         unsafe {
             let node = first.as_mut();
             debug_assert_ne!(node.size, 0);
 
-            let item = mem::replace(&mut node.values[0], MaybeUninit::uninit()).assume_init();
+            let item = node.take_front();
 
-            if node.size == 1 {
+            if node.size == 0 {
                 // the last item, deallocate it
-                let mut boxed = Box::from_raw(first.as_ptr());
-                if let Some(next) = boxed.next.as_mut() {
-                    next.as_mut().prev = None;
-                }
-                self.first = boxed.next;
-                if self.first.is_none() {
-                    // if this node was the last one, also remove it from the tail pointer
-                    self.last = None;
-                }
-            } else {
-                // more items, move them down
-                std::ptr::copy(
-                    &node.values[1] as *const _,
-                    &mut node.values[0] as *mut _,
-                    node.size - 1,
-                );
-                node.size -= 1;
+                self.unlink_node(*first);
             }
 
             self.len -= 1;
@@ -137,6 +339,17 @@ impl<T, const COUNT: usize> PackedLinkedList<T, COUNT> {
         }
     }
 
+    /// Pops the front element only if it matches the predicate
+    ///
+    /// Returns `None` and leaves the list unchanged if the list is empty or the value doesn't match
+    pub fn pop_front_if<F: FnOnce(&T) -> bool>(&mut self, f: F) -> Option<T> {
+        if self.iter().next().is_some_and(f) {
+            self.pop_front()
+        } else {
+            None
+        }
+    }
+
     /// Pops the back value and returns it
     pub fn pop_back(&mut self) -> Option<T> {
         let last = &mut self.last?;
@@ -144,30 +357,37 @@ impl<T, const COUNT: usize> PackedLinkedList<T, COUNT> {
             let node = last.as_mut();
             debug_assert_ne!(node.size, 0);
 
-            let item =
-                mem::replace(&mut node.values[node.size - 1], MaybeUninit::uninit()).assume_init();
+            let item = node.slot(node.size - 1).read();
 
-            if node.size == 1 {
+            node.size -= 1;
+            if node.size == 0 {
                 // the last item, deallocate it
-                let mut boxed = Box::from_raw(last.as_ptr());
-                if let Some(previous) = boxed.prev.as_mut() {
-                    previous.as_mut().next = None;
-                }
-                self.last = boxed.prev;
-                if self.last.is_none() {
-                    // if this node was the last one, also remove it from the tail pointer
-                    self.first = None;
-                }
-            } else {
-                // more items
-                node.size -= 1;
+                self.unlink_node(*last);
             }
             self.len -= 1;
             Some(item)
         }
     }
 
-    pub fn cursor_front(&self) -> Cursor<T, COUNT> {
+    /// Returns the element at the index, or `None` if the index is out of bounds
+    ///
+    /// This only has to walk the nodes and not every element, so it is O(n / COUNT)
+    pub fn get(&self, mut index: usize) -> Option<&T> {
+        let mut node = self.first;
+        while let Some(current) = node {
+            // SAFETY: All pointers should always point to valid memory
+            let current = unsafe { current.as_ref() };
+            if index < current.size {
+                // SAFETY: the index is in bounds, so the value is initialized
+                return Some(unsafe { &*current.slot(index) });
+            }
+            index -= current.size;
+            node = current.next;
+        }
+        None
+    }
+
+    pub fn cursor_front(&self) -> Cursor<'_, T, COUNT> {
         Cursor {
             node: self.first,
             index: 0,
@@ -175,7 +395,7 @@ impl<T, const COUNT: usize> PackedLinkedList<T, COUNT> {
         }
     }
 
-    pub fn cursor_back(&self) -> Cursor<T, COUNT> {
+    pub fn cursor_back(&self) -> Cursor<'_, T, COUNT> {
         Cursor {
             node: self.last,
             // point to the last element in the last node, or 0 if no node is found
@@ -187,7 +407,7 @@ impl<T, const COUNT: usize> PackedLinkedList<T, COUNT> {
         }
     }
 
-    pub fn cursor_mut_front(&mut self) -> CursorMut<T, COUNT> {
+    pub fn cursor_mut_front(&mut self) -> CursorMut<'_, T, COUNT> {
         CursorMut {
             node: self.first,
             index: 0,
@@ -195,7 +415,7 @@ impl<T, const COUNT: usize> PackedLinkedList<T, COUNT> {
         }
     }
 
-    pub fn cursor_mut_back(&mut self) -> CursorMut<T, COUNT> {
+    pub fn cursor_mut_back(&mut self) -> CursorMut<'_, T, COUNT> {
         CursorMut {
             node: self.last,
             // point to the last element in the last node, or 0 if no node is found
@@ -207,16 +427,393 @@ impl<T, const COUNT: usize> PackedLinkedList<T, COUNT> {
         }
     }
 
-    pub fn iter(&self) -> iter::Iter<T, COUNT> {
+    pub fn iter(&self) -> iter::Iter<'_, T, COUNT> {
         iter::Iter::new(self)
     }
 
-    pub fn iter_mut(&mut self) -> iter::IterMut<T, COUNT> {
+    pub fn iter_mut(&mut self) -> iter::IterMut<'_, T, COUNT> {
         iter::IterMut::new(self)
     }
 
+    /// Consumes the list and returns an iterator over the owned values, which frees every node as soon as all of
+    /// its values have been taken out
+    ///
+    /// This can be collected into a [LinkedList] directly, without putting the values into a `Vec` first.
+    /// It is the same as [PackedLinkedList::into_iter], the values that are not consumed are dropped with it
+    pub fn into_unpacked_iter(self) -> impl Iterator<Item = T> {
+        self.into_iter()
+    }
+
+    /// Calls the closure with the values of every node as a mutable slice, from front to back
+    ///
+    /// Every slice contains at most `COUNT` values, this is useful for bulk operations on the values
+    pub fn for_each_chunk_mut<F: FnMut(&mut [T])>(&mut self, mut f: F) {
+        let mut node = self.first;
+        while let Some(mut current) = node {
+            // SAFETY: All pointers should always point to valid memory
+            let current = unsafe { current.as_mut() };
+            f(current.as_mut_slice());
+            node = current.next;
+        }
+    }
+
+    /// Returns an iterator over the items that also yields whether the item is the first one of its node
+    pub fn iter_with_node_breaks(&self) -> impl Iterator<Item = (bool, &T)> {
+        let mut iter = self.iter();
+        std::iter::from_fn(move || {
+            let item = iter.next()?;
+            Some((iter.returned_node_start(), item))
+        })
+    }
+
+    /// Returns a mut iterator over the items that also yields whether the item is the first one of its node
+    pub fn iter_mut_with_node_breaks(&mut self) -> impl Iterator<Item = (bool, &mut T)> {
+        let mut iter = self.iter_mut();
+        std::iter::from_fn(move || {
+            let item = iter.next()?;
+            Some((iter.returned_node_start(), item))
+        })
+    }
+
+    /// Returns an iterator over the elements of this list followed by the elements of `other`
+    pub fn iter_chain<'a>(
+        &'a self,
+        other: &'a PackedLinkedList<T, COUNT>,
+    ) -> impl Iterator<Item = &'a T> {
+        self.iter().chain(other.iter())
+    }
+
+    /// Counts the elements that are contained in the range
+    ///
+    /// The list is not sorted, so this has to look at every element (O(n))
+    pub fn count_in_range<R: RangeBounds<T>>(&self, range: R) -> usize
+    where
+        T: PartialOrd,
+    {
+        self.iter().filter(|item| range.contains(*item)).count()
+    }
+
+    /// Returns the `k`-th largest element, where the largest element is the first one, or `None` if `k` is 0 or
+    /// larger than the length of the list
+    ///
+    /// This keeps the `k` largest elements seen so far in a heap, so it is O(n log k) without sorting the list
+    pub fn kth_largest(&self, k: usize) -> Option<T>
+    where
+        T: Ord + Clone,
+    {
+        if k == 0 || k > self.len() {
+            return None;
+        }
+        let mut largest = BinaryHeap::with_capacity(k + 1);
+        for item in self.iter() {
+            if largest.len() < k {
+                largest.push(Reverse(item));
+            } else if largest.peek().is_some_and(|Reverse(min)| item > *min) {
+                largest.pop();
+                largest.push(Reverse(item));
+            }
+        }
+        largest.pop().map(|Reverse(item)| item.clone())
+    }
+
+    /// Returns every value that is contained more than once, each only once and in the order in which the
+    /// values first appear in the list
+    pub fn duplicates(&self) -> Vec<&T>
+    where
+        T: Eq + Hash,
+    {
+        let mut counts = HashMap::new();
+        let mut distinct = Vec::new();
+        for item in self.iter() {
+            let count = counts.entry(item).or_insert(0);
+            if *count == 0 {
+                distinct.push(item);
+            }
+            *count += 1;
+        }
+        distinct
+            .into_iter()
+            .filter(|item| counts[item] > 1)
+            .collect()
+    }
+
+    /// Splits the list into a list of the elements matching the predicate and a list of the other elements,
+    /// preserving the order of the elements in both lists
+    ///
+    /// The predicate is called once per element. Runs of elements that end up in the same list are filled into
+    /// its nodes in one go
+    pub fn partition<F: FnMut(&T) -> bool>(self, mut f: F) -> (Self, Self) {
+        let mut matching = Self::new();
+        let mut rest = Self::new();
+        let mut iter = self.into_iter();
+        let mut next = iter.next().map(|item| {
+            let is_match = f(&item);
+            (item, is_match)
+        });
+        while let Some((first, is_match)) = next.take() {
+            let run = std::iter::once(first).chain(
+                std::iter::from_fn(|| {
+                    let item = iter.next()?;
+                    if f(&item) == is_match {
+                        Some(item)
+                    } else {
+                        // this item starts the next run
+                        next = Some((item, !is_match));
+                        None
+                    }
+                })
+                .fuse(),
+            );
+            if is_match {
+                matching.extend_bulk(run);
+            } else {
+                rest.extend_bulk(run);
+            }
+        }
+        (matching, rest)
+    }
+
+    /// Returns an iterator that removes and yields all elements matching the predicate.
+    /// Elements not matching the predicate stay in the list in their original order.
+    ///
+    /// If the iterator is dropped before it is exhausted, the remaining elements are left in the list.
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(
+        &mut self,
+        pred: F,
+    ) -> iter::ExtractIf<'_, T, F, COUNT> {
+        iter::ExtractIf::new(self, pred)
+    }
+
+    /// Returns an iterator over the items that skips consecutive repeated elements, yielding only the first one
+    /// of each run. Unlike [PackedLinkedList::dedup], this doesn't change the list
+    pub fn dedup_iter(&self) -> impl Iterator<Item = &T>
+    where
+        T: PartialEq,
+    {
+        let mut iter = self.iter();
+        let mut prev = None;
+        std::iter::from_fn(move || {
+            let item = iter.find(|item| prev.is_none_or(|prev| prev != *item))?;
+            prev = Some(item);
+            Some(item)
+        })
+    }
+
+    /// Removes consecutive repeated elements, keeping the first one of each run
+    ///
+    /// The remaining values are moved down inside their node, nodes that end up empty are removed.
+    /// If a comparison panics, the values that were not compared yet stay in the list
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        /// Moves the values that haven't been looked at yet down to the kept ones and fixes up the size of the
+        /// node when it is dropped, even if a comparison or a drop panicked
+        struct DedupGuard<'a, T, const COUNT: usize> {
+            list: &'a mut PackedLinkedList<T, COUNT>,
+            node: NonNull<Node<T, COUNT>>,
+            read: usize,
+            kept: usize,
+        }
+
+        impl<T, const COUNT: usize> Drop for DedupGuard<'_, T, COUNT> {
+            fn drop(&mut self) {
+                // SAFETY: the values from `read` to `size` haven't been moved or dropped yet
+                unsafe {
+                    let node = self.node.as_mut();
+                    let remaining = node.size - self.read;
+                    if self.read != self.kept {
+                        let values = node.slot_mut(0);
+                        std::ptr::copy(values.add(self.read), values.add(self.kept), remaining);
+                    }
+                    self.list.len -= self.read - self.kept;
+                    node.size = self.kept + remaining;
+                    if node.size == 0 {
+                        self.list.unlink_node(self.node);
+                    }
+                }
+            }
+        }
+
+        let mut prev_node: Option<NonNull<Node<T, COUNT>>> = None;
+        let mut node = self.first;
+        // SAFETY: All pointers should always point to valid memory, and all values from `start` to
+        // `start + size` are initialized. Every value is either moved down or dropped exactly once.
+        unsafe {
+            while let Some(mut current_ptr) = node {
+                let current = current_ptr.as_mut();
+                node = current.next;
+                let size = current.size;
+                let values = current.slot_mut(0);
+                let mut guard = DedupGuard {
+                    list: self,
+                    node: current_ptr,
+                    read: 0,
+                    kept: 0,
+                };
+                while guard.read < size {
+                    let value = values.add(guard.read);
+                    // the previous kept value might be in the previous node if nothing was kept in this one yet
+                    let previous = if guard.kept > 0 {
+                        Some(values.add(guard.kept - 1) as *const T)
+                    } else {
+                        prev_node.map(|prev| {
+                            let prev = prev.as_ref();
+                            prev.slot(prev.size - 1)
+                        })
+                    };
+
+                    if previous
+                        .map(|previous| *previous == *value)
+                        .unwrap_or(false)
+                    {
+                        // the value counts as removed before it is dropped, in case dropping it panics
+                        guard.read += 1;
+                        std::ptr::drop_in_place(value);
+                    } else {
+                        if guard.kept != guard.read {
+                            std::ptr::copy_nonoverlapping(value, values.add(guard.kept), 1);
+                        }
+                        guard.kept += 1;
+                        guard.read += 1;
+                    }
+                }
+
+                if guard.kept > 0 {
+                    prev_node = Some(current_ptr);
+                }
+                // fixes the size of the node and removes it if it is empty
+                drop(guard);
+            }
+        }
+    }
+
+    /// Moves the values towards the front of the list so that every node except the last one is full, and frees
+    /// the nodes that are emptied by that. The nodes kept for reuse by the node pool are freed as well.
+    ///
+    /// The capacity of a single node is fixed by `COUNT`, so the last node is never shrunk, only the amount of
+    /// nodes is minimized. This is O(n)
+    pub fn shrink_to_fit(&mut self) {
+        let mut node = self.first;
+        while let Some(mut current_ptr) = node {
+            // SAFETY: All pointers should always point to valid memory
+            unsafe {
+                let current = current_ptr.as_mut();
+                current.compact();
+                let mut next_ptr = match current.next {
+                    Some(next) => next,
+                    None => break,
+                };
+                if current.is_full() {
+                    node = Some(next_ptr);
+                    continue;
+                }
+
+                let next = next_ptr.as_mut();
+                let amount = (COUNT - current.size).min(next.size);
+                std::ptr::copy_nonoverlapping(next.slot(0), current.slot_mut(current.size), amount);
+                current.size += amount;
+                next.size -= amount;
+                if next.size == 0 {
+                    next.start = 0;
+                    // the current node is filled up further from the node after it in the next iteration
+                    self.unlink_node(next_ptr);
+                } else {
+                    next.start += amount;
+                    node = Some(next_ptr);
+                }
+            }
+        }
+        self.clear_node_pool();
+    }
+
+    /// The amount of allocated nodes in the list, not counting the nodes in the node pool
+    ///
+    /// This walks all nodes (O(n / COUNT))
+    pub fn node_count(&self) -> usize {
+        let mut count = 0;
+        let mut node = self.first;
+        while let Some(current) = node {
+            // SAFETY: All pointers should always point to valid memory
+            node = unsafe { current.as_ref() }.next;
+            count += 1;
+        }
+        count
+    }
+
+    /// Walks all nodes from the first one and restores `last`, the `prev` pointers of the nodes and the length
+    /// from the chain of `next` pointers, in case they got out of sync
+    ///
+    /// This should never be necessary, but it is useful to recover from and find bugs in the node bookkeeping
+    pub fn repair_endpoints(&mut self) {
+        let mut len = 0;
+        let mut prev = None;
+        let mut node = self.first;
+        while let Some(mut current) = node {
+            // SAFETY: All pointers should always point to valid memory
+            let current_ref = unsafe { current.as_mut() };
+            current_ref.prev = prev;
+            len += current_ref.size;
+            prev = Some(current);
+            node = current_ref.next;
+        }
+        self.last = prev;
+        self.len = len;
+    }
+
+    /// The sum of the sizes of all nodes, which should always be equal to [PackedLinkedList::len]
+    ///
+    /// This walks all nodes (O(n / COUNT)) and is mostly useful for checking the bookkeeping in tests
+    pub fn total_node_size(&self) -> usize {
+        let mut total = 0;
+        let mut node = self.first;
+        while let Some(current) = node {
+            // SAFETY: All pointers should always point to valid memory
+            let current = unsafe { current.as_ref() };
+            total += current.size;
+            node = current.next;
+        }
+        total
+    }
+
+    /// Pushes all items to the back of the list. Instead of checking the last node for every item like
+    /// [PackedLinkedList::push_back], every node is filled up completely in a tight loop before the next
+    /// node is allocated. The resulting list is the same as with repeated calls to `push_back`
+    fn extend_bulk<I: Iterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.peekable();
+        // only allocate a new node if there is an item for it, nodes are never empty
+        while iter.peek().is_some() {
+            // SAFETY: All pointers should always point to valid memory
+            if self
+                .last
+                .is_none_or(|node| unsafe { node.as_ref() }.is_full())
+            {
+                self.insert_node_end();
+            }
+            // SAFETY: the last node exists now and is not full
+            let node = unsafe { self.last.unwrap().as_mut() };
+            node.compact();
+            while !node.is_full() {
+                match iter.next() {
+                    Some(item) => {
+                        // SAFETY: the node is compacted and not full, so the slot after the values is free
+                        unsafe { node.slot_mut(node.size).write(item) };
+                        node.size += 1;
+                        self.len += 1;
+                    }
+                    None => return,
+                }
+            }
+        }
+    }
+
     fn insert_node_start(&mut self) {
-        let node = Some(allocate_nonnull(Node::new(None, self.first)));
+        let node = self.allocate_node(None, self.first);
+        self.link_node_start(node);
+    }
+
+    fn link_node_start(&mut self, node: NonNull<Node<T, COUNT>>) {
+        let node = Some(node);
         if let Some(first) = self.first.as_mut() {
             unsafe { first.as_mut().prev = node };
         }
@@ -226,8 +823,80 @@ impl<T, const COUNT: usize> PackedLinkedList<T, COUNT> {
         }
     }
 
+    /// Removes the node from the list and deallocates it
+    /// # Safety
+    /// The node must be part of this list and all values in it must have been moved out or dropped already
+    unsafe fn unlink_node(&mut self, node: NonNull<Node<T, COUNT>>) {
+        let (prev, next) = (node.as_ref().prev, node.as_ref().next);
+        match prev {
+            Some(mut prev) => prev.as_mut().next = next,
+            None => self.first = next,
+        }
+        match next {
+            Some(mut next) => next.as_mut().prev = prev,
+            None => self.last = prev,
+        }
+        self.free_node(node);
+    }
+
+    /// Allocates a new empty node, reusing a node from the pool if possible
+    fn allocate_node(
+        &mut self,
+        prev: Option<NonNull<Node<T, COUNT>>>,
+        next: Option<NonNull<Node<T, COUNT>>>,
+    ) -> NonNull<Node<T, COUNT>> {
+        match self.pool {
+            Some(node) => {
+                // SAFETY: pooled nodes are valid allocations that are not used by anything else
+                unsafe {
+                    self.pool = node.as_ref().next;
+                    node.as_ptr().write(Node::new(prev, next));
+                }
+                node
+            }
+            None => {
+                self.record_allocation();
+                allocate_nonnull(Node::new(prev, next))
+            }
+        }
+    }
+
+    /// Like [PackedLinkedList::allocate_node], but returns an error instead of aborting if the allocation fails
+    fn try_allocate_node(
+        &mut self,
+        prev: Option<NonNull<Node<T, COUNT>>>,
+        next: Option<NonNull<Node<T, COUNT>>>,
+    ) -> Result<NonNull<Node<T, COUNT>>, TryReserveError> {
+        match self.pool {
+            Some(_) => Ok(self.allocate_node(prev, next)),
+            None => {
+                let node = try_allocate_nonnull(Node::new(prev, next))?;
+                self.record_allocation();
+                Ok(node)
+            }
+        }
+    }
+
+    /// Deallocates a node or puts it into the pool
+    /// # Safety
+    /// The node must not be linked into the list anymore and all values in it must have been moved out or dropped already
+    unsafe fn free_node(&mut self, mut node: NonNull<Node<T, COUNT>>) {
+        if self.use_pool {
+            node.as_mut().next = self.pool;
+            self.pool = Some(node);
+        } else {
+            drop(Box::from_raw(node.as_ptr()));
+            self.record_deallocation();
+        }
+    }
+
     fn insert_node_end(&mut self) {
-        let node = Some(allocate_nonnull(Node::new(self.last, None)));
+        let node = self.allocate_node(self.last, None);
+        self.link_node_end(node);
+    }
+
+    fn link_node_end(&mut self, node: NonNull<Node<T, COUNT>>) {
+        let node = Some(node);
         if let Some(last) = self.last.as_mut() {
             unsafe { last.as_mut().next = node };
         }
@@ -250,18 +919,14 @@ impl<T, const COUNT: usize> IntoIterator for PackedLinkedList<T, COUNT> {
 impl<T, const COUNT: usize> FromIterator<T> for PackedLinkedList<T, COUNT> {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut list = PackedLinkedList::new();
-        for item in iter {
-            list.push_back(item);
-        }
+        list.extend_bulk(iter.into_iter());
         list
     }
 }
 
 impl<T, const COUNT: usize> Extend<T> for PackedLinkedList<T, COUNT> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        for item in iter {
-            self.push_back(item);
-        }
+        self.extend_bulk(iter.into_iter());
     }
 }
 
@@ -295,15 +960,46 @@ impl<T: PartialEq, const COUNT: usize> PartialEq for PackedLinkedList<T, COUNT>
     }
 }
 
+impl<T: PartialEq, const COUNT: usize> PartialEq<LinkedList<T>> for PackedLinkedList<T, COUNT> {
+    fn eq(&self, other: &LinkedList<T>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq, const COUNT: usize> PartialEq<PackedLinkedList<T, COUNT>> for LinkedList<T> {
+    fn eq(&self, other: &PackedLinkedList<T, COUNT>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+/// Converts the list into an array if it contains exactly `N` elements, otherwise the list is returned unchanged
+impl<T, const COUNT: usize, const N: usize> TryFrom<PackedLinkedList<T, COUNT>> for [T; N] {
+    type Error = PackedLinkedList<T, COUNT>;
+
+    fn try_from(list: PackedLinkedList<T, COUNT>) -> Result<Self, Self::Error> {
+        if list.len() != N {
+            return Err(list);
+        }
+        match <[T; N]>::try_from(list.into_iter().collect::<Vec<_>>()) {
+            Ok(array) => Ok(array),
+            Err(_) => unreachable!("the length was checked before"),
+        }
+    }
+}
+
 /// A single node in the packed linked list
 ///
 /// The node can have 1 to `COUNT` items.
 /// A node is never guaranteed to be full, even if it has a next node
 /// A node is always guaranteed to be non-empty
+///
+/// The items are stored in `values[start..start + size]`, so that popping from the front doesn't need to
+/// move all other values down. Indices passed to the methods of the node are relative to `start`.
 struct Node<T, const COUNT: usize> {
     prev: Option<NonNull<Node<T, COUNT>>>,
     next: Option<NonNull<Node<T, COUNT>>>,
     values: [MaybeUninit<T>; COUNT],
+    start: usize,
     size: usize,
 }
 
@@ -314,15 +1010,19 @@ impl<T: Debug, const COUNT: usize> Debug for Node<T, COUNT> {
             .field("next", &self.next)
             .field("values", &{
                 let mut str = String::from("[");
-                for i in 0..self.size {
-                    str.push_str(&format!("{:?}, ", unsafe { &*self.values[i].as_ptr() }))
+                for _ in 0..self.start {
+                    str.push_str("(uninit), ")
+                }
+                for item in self.as_slice() {
+                    str.push_str(&format!("{:?}, ", item))
                 }
-                for _ in self.size..COUNT {
+                for _ in self.start + self.size..COUNT {
                     str.push_str("(uninit), ")
                 }
                 str.push(']');
                 str
             })
+            .field("start", &self.start)
             .field("size", &self.size)
             .finish()
     }
@@ -336,6 +1036,7 @@ impl<T, const COUNT: usize> Node<T, COUNT> {
             // SAFETY: This is safe because we claim that the MaybeUninits are initialized, which they always are,
             // since any uninitialized memory is a valid MaybeUninit
             values: unsafe { MaybeUninit::uninit().assume_init() },
+            start: 0,
             size: 0,
         }
     }
@@ -345,12 +1046,53 @@ impl<T, const COUNT: usize> Node<T, COUNT> {
         self.size == COUNT
     }
 
+    /// A pointer to the value at the index
+    /// The pointer is only valid to read from if the index is in bounds
+    fn slot(&self, index: usize) -> *const T {
+        debug_assert!(self.start + index <= COUNT);
+        // SAFETY: the offset is at most one past the end of the array
+        unsafe { self.values.as_ptr().add(self.start + index) as *const T }
+    }
+
+    /// A mutable pointer to the value at the index
+    /// The pointer is only valid to read from if the index is in bounds
+    fn slot_mut(&mut self, index: usize) -> *mut T {
+        debug_assert!(self.start + index <= COUNT);
+        // SAFETY: the offset is at most one past the end of the array
+        unsafe { self.values.as_mut_ptr().add(self.start + index) as *mut T }
+    }
+
+    /// The initialized values of the node
+    fn as_slice(&self) -> &[T] {
+        // SAFETY: all values from `start` to `start + size` are initialized
+        unsafe { std::slice::from_raw_parts(self.slot(0), self.size) }
+    }
+
+    /// The initialized values of the node
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: all values from `start` to `start + size` are initialized
+        unsafe { std::slice::from_raw_parts_mut(self.slot_mut(0), self.size) }
+    }
+
+    /// Moves all values down to the start of the array, so that there is space at the back
+    fn compact(&mut self) {
+        if self.start != 0 {
+            let values = self.values.as_mut_ptr();
+            // SAFETY: both ranges are in bounds, `ptr::copy` handles the overlap
+            unsafe { std::ptr::copy(values.add(self.start), values, self.size) };
+            self.start = 0;
+        }
+    }
+
     /// Pushes a new value to the back
     /// # Safety
     /// The node must not be full
     unsafe fn push_back(&mut self, element: T) {
         debug_assert!(self.size < COUNT);
-        self.values[self.size] = MaybeUninit::new(element);
+        if self.start + self.size == COUNT {
+            self.compact();
+        }
+        self.slot_mut(self.size).write(element);
         self.size += 1;
     }
 
@@ -359,16 +1101,16 @@ impl<T, const COUNT: usize> Node<T, COUNT> {
     /// The node must not be full
     unsafe fn push_front(&mut self, element: T) {
         debug_assert!(self.size < COUNT);
-        // copy all values up
-        if COUNT > 1 {
-            std::ptr::copy(
-                &self.values[0] as *const _,
-                &mut self.values[1] as *mut _,
-                self.size,
-            );
+        if self.start > 0 {
+            // there is free space in front of the values
+            self.start -= 1;
+        } else if COUNT > 1 {
+            // copy all values up
+            let values = self.values.as_mut_ptr();
+            std::ptr::copy(values, values.add(1), self.size);
         }
 
-        self.values[0] = MaybeUninit::new(element);
+        self.slot_mut(0).write(element);
         self.size += 1;
     }
 
@@ -379,14 +1121,52 @@ impl<T, const COUNT: usize> Node<T, COUNT> {
     unsafe fn insert(&mut self, element: T, index: usize) {
         debug_assert!(self.size < COUNT);
         debug_assert!(self.size > index);
-        // copy all values up
-        for i in (index..self.size).rev() {
-            println!("{}", i);
-            self.values[i + 1] = mem::replace(&mut self.values[i], MaybeUninit::uninit());
+        if self.start + self.size == COUNT {
+            self.compact();
         }
-        self.values[index] = MaybeUninit::new(element);
+        // copy all values up
+        let slot = self.slot_mut(index);
+        std::ptr::copy(slot, slot.add(1), self.size - index);
+        slot.write(element);
         self.size += 1;
     }
+
+    /// Inserts a new value at the index, which may also be the index after the last value
+    /// # Safety
+    /// The node must not be full and the index must not be larger than the size
+    unsafe fn insert_at(&mut self, element: T, index: usize) {
+        if index == self.size {
+            self.push_back(element);
+        } else {
+            self.insert(element, index);
+        }
+    }
+
+    /// Takes the first value out of the node without moving the other values
+    /// # Safety
+    /// The node must not be empty
+    unsafe fn take_front(&mut self) -> T {
+        debug_assert_ne!(self.size, 0);
+        let item = self.slot(0).read();
+        self.size -= 1;
+        self.start = if self.size == 0 { 0 } else { self.start + 1 };
+        item
+    }
+
+    /// Takes the value at the index out of the node, moving the values after it down
+    /// # Safety
+    /// The index must not be out of bounds
+    unsafe fn remove(&mut self, index: usize) -> T {
+        debug_assert!(self.size > index);
+        let slot = self.slot_mut(index);
+        let item = slot.read();
+        std::ptr::copy(slot.add(1), slot, self.size - index - 1);
+        self.size -= 1;
+        if self.size == 0 {
+            self.start = 0;
+        }
+        item
+    }
 }
 
 macro_rules! implement_cursor {
@@ -394,7 +1174,7 @@ macro_rules! implement_cursor {
         impl<'a, T, const COUNT: usize> $cursor<'a, T, COUNT> {
             pub fn get(&self) -> Option<&T> {
                 self.node
-                    .map(|nn| unsafe { nn.as_ref().values[self.index].as_ptr().as_ref().unwrap() })
+                    .map(|nn| unsafe { &*nn.as_ref().slot(self.index) })
             }
 
             pub fn move_next(&mut self) {
@@ -467,7 +1247,7 @@ impl<'a, T, const COUNT: usize> CursorMut<'a, T, COUNT> {
         let index = self.index;
         self.node
             .as_mut()
-            .map(|nn| unsafe { nn.as_mut().values[index].as_mut_ptr().as_mut().unwrap() })
+            .map(|nn| unsafe { &mut *nn.as_mut().slot_mut(index) })
     }
 
     pub fn replace(&mut self, _element: T) -> Option<T> {
@@ -517,33 +1297,84 @@ impl<'a, T, const COUNT: usize> CursorMut<'a, T, COUNT> {
                             let next_node = next_node
                                 .unwrap_or_else(|| unsafe { core::hint::unreachable_unchecked() });
                             // SAFETY: the node is not full, because `need_allocate` is false
-                            unsafe { next_node.push_back(element) };
+                            unsafe { next_node.push_front(element) };
                         }
                     }
                     // SAFETY: the node is not full and the index is not out of bounds
                     (false, false) => unsafe { current.insert(element, self.index + 1) },
                     (false, true) => {
-                        // we need to copy some values to the next node, always allocate a new one to avoid needing to copy too many values
-                        // nodes that are not very full will make insertions faster later, so we prefer them
-                        // this is a bad though if we repeatedly insert at the same position here, so maybe we want to insert it into the next node anyways
-                        unsafe {
-                            let mut next = self.allocate_new_node_after();
-                            let mut next = next.as_mut();
-                            // example: current node of COUNT=8 is full, we want to insert at 7
-                            // self.index=6
-                            // copy 2 values to the next node, 7 & 8
-                            let to_copy = current.size - self.index;
-                            std::ptr::copy_nonoverlapping(
-                                current.values[self.index + 1].as_ptr(),
-                                next.values[0].as_mut_ptr(),
-                                to_copy,
-                            );
-                            //for i in self.index..5 {
-                            //
-                            //}
-                            current.values[self.index + 1] = MaybeUninit::new(element);
-                            next.size = to_copy;
-                            current.size = self.index + 2;
+                        // SAFETY: the node is full and the index is not the last one of the node
+                        let (node, index) =
+                            unsafe { self.insert_into_full_node(current_node, element) };
+                        // the element of the cursor might have been moved into the new node,
+                        // it is always right before the new element
+                        self.node = Some(node);
+                        self.index = index - 1;
+                    }
+                }
+                self.list.len += 1;
+            }
+        }
+    }
+
+    /// Inserts a new element before the element this cursor is pointing to.  
+    /// If the cursor is pointing at the ghost node, the item gets inserted at the end of the list  
+    /// The cursor keeps pointing to the same element.  
+    pub fn insert_before(&mut self, element: T) {
+        match self.node {
+            None => self.list.push_back(element),
+            Some(mut current_node) => {
+                let current = unsafe { current_node.as_mut() };
+
+                // There are several cases here
+                // 1. we insert an item into the node, and it is not full
+                // 2. we insert an item before the first element of the node, and it is full
+                // 3. we insert an item into the middle of the node, and it is full
+                match (self.index == 0, current.is_full()) {
+                    (true, false) => {
+                        // SAFETY: the node is not full
+                        unsafe { current.push_front(element) };
+                        self.index += 1;
+                    }
+                    (false, false) => {
+                        // SAFETY: the node is not full and the index is not out of bounds
+                        unsafe { current.insert(element, self.index) };
+                        self.index += 1;
+                    }
+                    (true, true) => {
+                        // append it to the previous node if it is not full, otherwise allocate a new node
+                        // between the previous and the current node
+                        let prev = current.prev;
+                        match prev {
+                            Some(mut prev) if !unsafe { prev.as_ref() }.is_full() => {
+                                // SAFETY: the node is not full
+                                unsafe { prev.as_mut().push_back(element) };
+                            }
+                            _ => {
+                                // the new node is allocated after the previous node, or at the start of the list
+                                self.node = prev;
+                                unsafe {
+                                    let mut new_node = self.allocate_new_node_after();
+                                    new_node.as_mut().push_back(element);
+                                }
+                                self.node = Some(current_node);
+                            }
+                        }
+                    }
+                    (false, true) => {
+                        // inserting before the element is the same as inserting after the one before it
+                        self.index -= 1;
+                        // SAFETY: the node is full and the index is not the last one of the node
+                        let (mut node, index) =
+                            unsafe { self.insert_into_full_node(current_node, element) };
+                        // the element of the cursor is right after the new element
+                        let node_ref = unsafe { node.as_mut() };
+                        if index + 1 < node_ref.size {
+                            self.node = Some(node);
+                            self.index = index + 1;
+                        } else {
+                            self.node = node_ref.next;
+                            self.index = 0;
                         }
                     }
                 }
@@ -552,16 +1383,111 @@ impl<'a, T, const COUNT: usize> CursorMut<'a, T, COUNT> {
         }
     }
 
-    pub fn insert_before(&mut self, _element: T) {}
+    /// Moves all elements of `other` into the list after the element this cursor is pointing to.  
+    /// If the cursor is pointing at the ghost node, the elements get inserted at the start of the list  
+    /// The nodes of `other` are relinked instead of copied, only the current node might be split in two.  
+    /// The cursor position will not change.  
+    pub fn splice_after(&mut self, mut other: PackedLinkedList<T, COUNT>) {
+        let (mut other_first, mut other_last) = match (other.first.take(), other.last.take()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return,
+        };
+        // `other` does not own any nodes anymore, so dropping it is fine
+        let other_len = mem::replace(&mut other.len, 0);
+
+        // SAFETY: All pointers should always point to valid memory
+        unsafe {
+            let next = match self.node {
+                None => self.list.first.replace(other_first),
+                Some(mut current_node) => {
+                    if self.index != current_node.as_ref().size - 1 {
+                        // the cursor is in the middle of the node, move the values behind it to a new node
+                        self.split_node(current_node, self.index + 1);
+                    }
+                    current_node.as_mut().next.replace(other_first)
+                }
+            };
+
+            other_first.as_mut().prev = self.node;
+            other_last.as_mut().next = next;
+            match next {
+                None => self.list.last = Some(other_last),
+                Some(mut next) => next.as_mut().prev = Some(other_last),
+            }
+        }
+        self.list.len += other_len;
+    }
+
+    /// Inserts the element after the cursor into the full node, using the insert policy of the list.
+    /// Returns the node and the index that the element ended up at
+    /// # Safety
+    /// The node must be the node of the cursor, it must be full and the cursor must not point to its last element
+    unsafe fn insert_into_full_node(
+        &mut self,
+        mut current_node: NonNull<Node<T, COUNT>>,
+        element: T,
+    ) -> (NonNull<Node<T, COUNT>>, usize) {
+        let index = self.index + 1;
+        match self.list.insert_policy {
+            InsertPolicy::PreferNext
+                if current_node
+                    .as_ref()
+                    .next
+                    .is_some_and(|next| !next.as_ref().is_full()) =>
+            {
+                let current = current_node.as_mut();
+                let mut next = current.next.unwrap();
+                current.size -= 1;
+                next.as_mut().push_front(current.slot(current.size).read());
+                current.insert_at(element, index);
+                (current_node, index)
+            }
+            InsertPolicy::AlwaysSplit | InsertPolicy::PreferNext => {
+                // the node is split right at the insertion point, so the element is appended to it
+                self.split_node(current_node, index);
+                current_node.as_mut().push_back(element);
+                (current_node, index)
+            }
+            InsertPolicy::BalancedSplit => {
+                let mid = COUNT / 2;
+                let mut next = self.split_node(current_node, mid);
+                if index <= mid {
+                    current_node.as_mut().insert_at(element, index);
+                    (current_node, index)
+                } else {
+                    next.as_mut().insert_at(element, index - mid);
+                    (next, index - mid)
+                }
+            }
+        }
+    }
+
+    /// Moves the values of the node from `at` on into a new node after it and returns the new node
+    /// # Safety
+    /// The node must be the node of the cursor and `at` must be smaller than the size of the node
+    unsafe fn split_node(
+        &mut self,
+        mut node: NonNull<Node<T, COUNT>>,
+        at: usize,
+    ) -> NonNull<Node<T, COUNT>> {
+        let mut split = self.allocate_new_node_after();
+        let current = node.as_mut();
+        let new = split.as_mut();
+        let to_move = current.size - at;
+        std::ptr::copy_nonoverlapping(current.slot(at), new.slot_mut(0), to_move);
+        new.size = to_move;
+        current.size = at;
+        split
+    }
 
     /// allocates a new node after the cursor
     /// if self.node is None, it allocates the node at the start of the list
     /// # Safety
     /// The node must immediately be filled with at least on element, since an empty node is not a valid state
     unsafe fn allocate_new_node_after(&mut self) -> NonNull<Node<T, COUNT>> {
-        let mut new_node = allocate_nonnull(Node::new(
+        let mut new_node = self.list.allocate_node(
             self.node, None, // will be replaced in the match below
-        ));
+        );
 
         match self.node {
             None => {
@@ -573,7 +1499,12 @@ impl<'a, T, const COUNT: usize> CursorMut<'a, T, COUNT> {
                 self.list.first = Some(new_node);
             }
             Some(mut node) => {
-                new_node.as_mut().next = node.as_ref().next;
+                let next = node.as_ref().next;
+                match next {
+                    None => self.list.last = Some(new_node),
+                    Some(mut next) => next.as_mut().prev = Some(new_node),
+                }
+                new_node.as_mut().next = next;
                 node.as_mut().next = Some(new_node);
             }
         }
@@ -585,7 +1516,6 @@ mod iter {
     use super::{Node, PackedLinkedList};
     use std::marker::PhantomData;
     use std::mem;
-    use std::mem::MaybeUninit;
     use std::ptr::NonNull;
 
     #[derive(Debug)]
@@ -601,6 +1531,11 @@ mod iter {
                 index: 0,
             }
         }
+
+        /// Whether the item returned last was the first item of its node
+        pub(super) fn returned_node_start(&self) -> bool {
+            self.index == 1
+        }
     }
 
     impl<'a, T, const COUNT: usize> Iterator for Iter<'a, T, COUNT> {
@@ -613,7 +1548,7 @@ mod iter {
             unsafe {
                 if node.size > self.index {
                     // take more
-                    let item = node.values[self.index].as_ptr().as_ref().unwrap();
+                    let item = &*node.slot(self.index);
                     self.index += 1;
                     Some(item)
                 } else {
@@ -623,7 +1558,7 @@ mod iter {
                     self.node = Some(next_node);
                     // a node should never be empty
                     debug_assert_ne!(next_node.size, 0);
-                    Some(next_node.values[0].as_ptr().as_ref().unwrap())
+                    Some(&*next_node.slot(0))
                 }
             }
         }
@@ -644,6 +1579,11 @@ mod iter {
                 _marker: PhantomData,
             }
         }
+
+        /// Whether the item returned last was the first item of its node
+        pub(super) fn returned_node_start(&self) -> bool {
+            self.index == 1
+        }
     }
 
     impl<'a, T: 'a, const COUNT: usize> Iterator for IterMut<'a, T, COUNT> {
@@ -657,8 +1597,7 @@ mod iter {
                 let node = node.as_mut();
                 if node.size > self.index {
                     // take more
-                    let ptr = node.values[self.index].as_ptr() as *mut T;
-                    let item = ptr.as_mut().unwrap();
+                    let item = &mut *node.slot_mut(self.index);
                     self.index += 1;
 
                     Some(item)
@@ -669,8 +1608,7 @@ mod iter {
                     self.index = 1;
                     self.node = Some(next_node);
                     // a node should never be empty
-                    let ptr = next_node.as_mut().values[0].as_ptr() as *mut T;
-                    Some(ptr.as_mut().unwrap())
+                    Some(&mut *next_node.as_mut().slot_mut(0))
                 }
             }
         }
@@ -689,7 +1627,9 @@ mod iter {
     }
 
     impl<T, const COUNT: usize> IntoIter<T, COUNT> {
-        pub(super) fn new(list: PackedLinkedList<T, COUNT>) -> Self {
+        pub(super) fn new(mut list: PackedLinkedList<T, COUNT>) -> Self {
+            // the pooled nodes are not needed by the iterator
+            list.clear_node_pool();
             let iter = Self {
                 node: list.first.map(|nn| unsafe { Box::from_raw(nn.as_ptr()) }),
                 index: 0,
@@ -706,7 +1646,7 @@ mod iter {
         fn next(&mut self) -> Option<Self::Item> {
             // take the node. the node has to either be returned or replaced by a new one. the None left
             // behind here is *not* a valid state
-            let mut node = self.node.take()?;
+            let node = self.node.take()?;
 
             // SAFETY: see more detailed comments
             unsafe {
@@ -714,8 +1654,7 @@ mod iter {
                     // take more items from the node
                     // take out the item and replace it with uninitialized memory
                     // the index pointer is increased, so no one will access this again
-                    let item = mem::replace(&mut node.values[self.index], MaybeUninit::uninit())
-                        .assume_init();
+                    let item = node.slot(self.index).read();
                     self.index += 1;
                     // re-insert the node
                     self.node = Some(node);
@@ -731,14 +1670,62 @@ mod iter {
                     debug_assert_ne!(next_node.size, 0);
                     self.node = Some(next_node);
                     // see comment above
-                    Some(
-                        mem::replace(
-                            &mut self.node.as_mut().unwrap().values[0],
-                            MaybeUninit::uninit(),
-                        )
-                        .assume_init(),
-                    )
+                    Some(self.node.as_ref().unwrap().slot(0).read())
+                }
+            }
+        }
+    }
+
+    pub struct ExtractIf<'a, T, F: FnMut(&mut T) -> bool, const COUNT: usize> {
+        list: &'a mut PackedLinkedList<T, COUNT>,
+        node: Option<NonNull<Node<T, COUNT>>>,
+        index: usize,
+        pred: F,
+    }
+
+    impl<'a, T, F: FnMut(&mut T) -> bool, const COUNT: usize> ExtractIf<'a, T, F, COUNT> {
+        pub(super) fn new(list: &'a mut PackedLinkedList<T, COUNT>, pred: F) -> Self {
+            Self {
+                node: list.first,
+                list,
+                index: 0,
+                pred,
+            }
+        }
+    }
+
+    impl<'a, T, F: FnMut(&mut T) -> bool, const COUNT: usize> Iterator for ExtractIf<'a, T, F, COUNT> {
+        type Item = T;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            // SAFETY: assume that all pointers point to the correct nodes,
+            // and that the sizes of the nodes are set correctly
+            unsafe {
+                while let Some(mut node_ptr) = self.node {
+                    let node = node_ptr.as_mut();
+                    if self.index >= node.size {
+                        self.node = node.next;
+                        self.index = 0;
+                        continue;
+                    }
+
+                    if !(self.pred)(&mut *node.slot_mut(self.index)) {
+                        self.index += 1;
+                        continue;
+                    }
+
+                    let item = node.remove(self.index);
+                    self.list.len -= 1;
+
+                    if node.size == 0 {
+                        // an empty node is not a valid state, remove it
+                        self.node = node.next;
+                        self.index = 0;
+                        self.list.unlink_node(node_ptr);
+                    }
+                    return Some(item);
                 }
+                None
             }
         }
     }
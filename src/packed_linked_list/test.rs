@@ -1,4 +1,6 @@
 use super::*;
+use crate::packed_linked_list;
+use std::convert::TryFrom;
 
 #[test]
 fn empty_unit_list() {
@@ -28,7 +30,7 @@ fn push_front_multiple_nodes_count_2() {
 
 #[test]
 fn pop_front() {
-    let mut list = create_sized_list::<_, 2>(&[1, 2, 3, 4]);
+    let mut list = packed_linked_list![2; 1, 2, 3, 4];
     assert_eq!(list.pop_front(), Some(1));
     assert_eq!(list.pop_front(), Some(2));
     assert_eq!(list.pop_front(), Some(3));
@@ -50,7 +52,7 @@ fn iter_single_node() {
 
 #[test]
 fn into_iter() {
-    let mut iter = create_list(&[1, 2, 3]).into_iter();
+    let mut iter = packed_linked_list![8; 1, 2, 3].into_iter();
     assert_eq!(iter.next(), Some(1));
     assert_eq!(iter.next(), Some(2));
     assert_eq!(iter.next(), Some(3));
@@ -59,7 +61,7 @@ fn into_iter() {
 
 #[test]
 fn iter_mut() {
-    let mut list = create_list(&[1, 2, 3, 4]);
+    let mut list = packed_linked_list![8; 1, 2, 3, 4];
     let mut iter_mut = list.iter_mut();
     *iter_mut.next().unwrap() = 10;
     assert!([10, 2, 3, 4].iter().zip(list.iter()).all(|(a, b)| a == b));
@@ -78,7 +80,7 @@ fn from_iter() {
 
 #[test]
 fn get_cursor() {
-    let list = create_list(&[1, 2, 3, 4, 5, 6]);
+    let list = packed_linked_list![8; 1, 2, 3, 4, 5, 6];
     let mut cursor = list.cursor_front();
     assert_eq!(cursor.get(), Some(&1));
     cursor.move_next();
@@ -103,7 +105,7 @@ fn get_cursor() {
 #[test]
 #[ignore]
 fn insert_cursor() {
-    let mut list = create_list(&[1, 2, 3, 4, 5, 6]);
+    let mut list = packed_linked_list![8; 1, 2, 3, 4, 5, 6];
     let mut cursor = list.cursor_mut_front();
     cursor.move_prev();
     cursor.move_prev();
@@ -116,7 +118,7 @@ fn insert_cursor() {
     assert_eq!(cursor.replace(12), Some(1));
     assert_eq!(cursor.get(), Some(&12));
     assert_eq!(cursor.remove(), Some(12));
-    assert_eq!(list, create_list(&[0, 11, 2, 3, 4, 5, 100]));
+    assert_eq!(list, packed_linked_list![8; 0, 11, 2, 3, 4, 5, 100]);
 }
 
 #[test]
@@ -125,19 +127,814 @@ fn insert_after_cursor() {
     let mut cursor = list.cursor_mut_front();
     // case 3
     cursor.insert_after(11);
-    assert_eq!(list, create_list(&[1, 11, 2, 3]));
+    assert_eq!(list, packed_linked_list![8; 1, 11, 2, 3]);
 
-    let mut list = create_sized_list::<_, 4>(&[1, 2, 3, 4]);
+    let mut list = packed_linked_list![4; 1, 2, 3, 4];
     let mut cursor = list.cursor_mut_front();
     // case 4
     cursor.insert_after(11);
     assert_eq!(list, create_sized_list(&[1, 11, 2, 3, 4]));
 }
 
-fn create_list<T: Clone>(iter: &[T]) -> PackedLinkedList<T, 8> {
-    iter.into_iter().cloned().collect()
+#[test]
+fn insert_before_cursor() {
+    let mut list = packed_linked_list![4; 1, 2, 3];
+    let mut cursor = list.cursor_mut_front();
+    cursor.move_next();
+    // case 1
+    cursor.insert_before(11);
+    assert_eq!(cursor.get(), Some(&2));
+    // case 1 at the start of the node
+    cursor.move_prev();
+    cursor.move_prev();
+    cursor.insert_before(10);
+    assert_eq!(cursor.get(), Some(&1));
+    assert_eq!(list, create_sized_list(&[10, 1, 11, 2, 3]));
+
+    // case 2, before the first element of the first node
+    let mut list = packed_linked_list![4; 1, 2, 3, 4];
+    let mut cursor = list.cursor_mut_front();
+    cursor.insert_before(0);
+    assert_eq!(cursor.get(), Some(&1));
+    cursor.move_prev();
+    assert_eq!(cursor.get(), Some(&0));
+    assert_eq!(list, create_sized_list(&[0, 1, 2, 3, 4]));
+    assert_eq!(list.node_count(), 2);
+
+    // case 1, before the first element of a node whose previous node is full
+    let mut list = packed_linked_list![4; 1, 2, 3, 4, 5, 6];
+    let mut cursor = list.cursor_mut_front();
+    for _ in 0..4 {
+        cursor.move_next();
+    }
+    cursor.insert_before(10);
+    assert_eq!(cursor.get(), Some(&5));
+    assert_eq!(list, create_sized_list(&[1, 2, 3, 4, 10, 5, 6]));
+    assert_eq!(list.node_count(), 2);
+
+    // case 2, the previous node is full as well
+    let mut list = packed_linked_list![4; 1, 2, 3, 4, 5, 6, 7, 8];
+    let mut cursor = list.cursor_mut_front();
+    for _ in 0..4 {
+        cursor.move_next();
+    }
+    cursor.insert_before(10);
+    assert_eq!(cursor.get(), Some(&5));
+    cursor.move_prev();
+    assert_eq!(cursor.get(), Some(&10));
+    cursor.move_prev();
+    assert_eq!(cursor.get(), Some(&4));
+    assert_eq!(list, create_sized_list(&[1, 2, 3, 4, 10, 5, 6, 7, 8]));
+    assert_eq!(list.node_count(), 3);
+    assert_eq!(list.total_node_size(), 9);
+
+    // case 2, the previous node has space
+    let mut list = packed_linked_list![4; 1, 2, 3, 4, 5];
+    list.pop_front();
+    list.push_front(0);
+    list.push_front(-1);
+    // the nodes are [-1], [0, 2, 3, 4], [5]
+    let mut cursor = list.cursor_mut_front();
+    cursor.move_next();
+    cursor.insert_before(10);
+    assert_eq!(cursor.get(), Some(&0));
+    assert_eq!(list, create_sized_list(&[-1, 10, 0, 2, 3, 4, 5]));
+    assert_eq!(list.node_count(), 3);
+
+    // the ghost node
+    let mut list = packed_linked_list![4; 1, 2];
+    let mut cursor = list.cursor_mut_front();
+    cursor.move_prev();
+    cursor.insert_before(3);
+    assert_eq!(cursor.get(), None);
+    cursor.move_prev();
+    assert_eq!(cursor.get(), Some(&3));
+    assert_eq!(list, create_sized_list(&[1, 2, 3]));
+    assert_eq!(list.len(), 3);
+}
+
+#[test]
+fn insert_before_policies() {
+    for policy in [
+        InsertPolicy::AlwaysSplit,
+        InsertPolicy::PreferNext,
+        InsertPolicy::BalancedSplit,
+    ]
+    .iter()
+    {
+        let mut expected = (0..12).map(|i| i * 100).collect::<Vec<_>>();
+        let mut list = create_sized_list::<_, 4>(&expected);
+        list.set_insert_policy(*policy);
+
+        let mut position = 7;
+        for i in 0..40 {
+            let mut cursor = list.cursor_mut_front();
+            for _ in 0..position {
+                cursor.move_next();
+            }
+            cursor.insert_before(i);
+            assert_eq!(cursor.get(), Some(&expected[position]), "{:?}", policy);
+            cursor.move_prev();
+            assert_eq!(cursor.get(), Some(&i), "{:?}", policy);
+            expected.insert(position, i);
+            position = (position * 7 + 3) % expected.len();
+        }
+
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            expected,
+            "{:?}",
+            policy
+        );
+        assert_eq!(list.len(), expected.len());
+        assert_eq!(list.total_node_size(), list.len());
+    }
+}
+
+#[test]
+fn extract_if() {
+    let mut list = packed_linked_list![2; 1, 2, 3, 4, 5, 6];
+    let evens = list.extract_if(|item| *item % 2 == 0).collect::<Vec<_>>();
+    assert_eq!(evens, vec![2, 4, 6]);
+    assert_eq!(list, create_sized_list(&[1, 3, 5]));
+    assert_eq!(list.len(), 3);
+
+    let mut list = packed_linked_list![1; 1, 2, 3, 4, 5, 6];
+    {
+        let mut iter = list.extract_if(|item| *item % 2 == 0);
+        assert_eq!(iter.next(), Some(2));
+    }
+    assert_eq!(list, create_sized_list(&[1, 3, 4, 5, 6]));
+    assert_eq!(list.len(), 5);
+}
+
+#[test]
+fn insert_policies() {
+    for policy in [
+        InsertPolicy::AlwaysSplit,
+        InsertPolicy::PreferNext,
+        InsertPolicy::BalancedSplit,
+    ]
+    .iter()
+    {
+        let mut expected = (0..12).map(|i| i * 100).collect::<Vec<_>>();
+        let mut list = create_sized_list::<_, 4>(&expected);
+        list.set_insert_policy(*policy);
+        assert_eq!(list.insert_policy(), *policy);
+
+        let mut position = 7;
+        for i in 0..40 {
+            let mut cursor = list.cursor_mut_front();
+            for _ in 0..position {
+                cursor.move_next();
+            }
+            cursor.insert_after(i);
+            assert_eq!(cursor.get(), Some(&expected[position]));
+            expected.insert(position + 1, i);
+            position = (position * 7 + 3) % expected.len();
+        }
+
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            expected,
+            "{:?}",
+            policy
+        );
+        assert_eq!(list.len(), expected.len());
+        assert_eq!(list.total_node_size(), list.len());
+    }
+}
+
+#[test]
+fn insert_after_full_node_moves_cursor() {
+    for policy in [
+        InsertPolicy::AlwaysSplit,
+        InsertPolicy::PreferNext,
+        InsertPolicy::BalancedSplit,
+    ]
+    .iter()
+    {
+        let mut list = create_sized_list::<_, 4>(&["1", "2", "3", "4"].map(String::from));
+        list.set_insert_policy(*policy);
+
+        let mut cursor = list.cursor_mut_front();
+        cursor.move_next();
+        cursor.move_next();
+        cursor.insert_after("10".to_string());
+        *cursor.get_mut().unwrap() = "99".to_string();
+        cursor.move_next();
+        assert_eq!(cursor.get().map(String::as_str), Some("10"), "{:?}", policy);
+        cursor.move_next();
+        assert_eq!(cursor.get().map(String::as_str), Some("4"), "{:?}", policy);
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.get().map(String::as_str), Some("99"), "{:?}", policy);
+
+        assert_eq!(
+            list,
+            create_sized_list(&["1", "2", "99", "10", "4"].map(String::from)),
+            "{:?}",
+            policy
+        );
+        assert_eq!(list.total_node_size(), list.len());
+    }
+}
+
+#[test]
+fn insert_policy_node_sizes() {
+    // inserting in the middle of the full first node
+    let insert = |policy| {
+        let mut list = packed_linked_list![4; 1, 2, 3, 4, 5];
+        list.set_insert_policy(policy);
+        let mut cursor = list.cursor_mut_front();
+        cursor.insert_after(10);
+        assert_eq!(list, create_sized_list(&[1, 10, 2, 3, 4, 5]));
+        list.node_count()
+    };
+    // the values after the insertion point are moved to a new node
+    assert_eq!(insert(InsertPolicy::AlwaysSplit), 3);
+    // the last value is moved to the next node, which has space
+    assert_eq!(insert(InsertPolicy::PreferNext), 2);
+    assert_eq!(insert(InsertPolicy::BalancedSplit), 3);
+}
+
+#[test]
+fn splice_after_cursor() {
+    let mut list = packed_linked_list![4; 1, 2, 3, 4, 5, 6];
+    let mut cursor = list.cursor_mut_front();
+    cursor.move_next();
+    cursor.splice_after(create_sized_list(&[10, 11, 12, 13]));
+    assert_eq!(cursor.get(), Some(&2));
+    assert_eq!(list, create_sized_list(&[1, 2, 10, 11, 12, 13, 3, 4, 5, 6]));
+    assert_eq!(list.len(), 10);
+
+    let mut list = packed_linked_list![4; 1, 2];
+    let mut cursor = list.cursor_mut_back();
+    cursor.splice_after(create_sized_list(&[3, 4]));
+    cursor.move_next();
+    cursor.move_next();
+    cursor.move_next();
+    cursor.splice_after(create_sized_list(&[0]));
+    assert_eq!(list, create_sized_list(&[0, 1, 2, 3, 4]));
+    assert_eq!(list.len(), 5);
+    assert_eq!(list.pop_back(), Some(4));
+}
+
+#[test]
+fn eq_linked_list() {
+    let packed = packed_linked_list![2; 1, 2, 3, 4, 5];
+    let plain = [1, 2, 3, 4, 5].iter().cloned().collect::<LinkedList<_>>();
+    assert_eq!(packed, plain);
+    assert_eq!(plain, packed);
+
+    let shorter = [1, 2, 3, 4].iter().cloned().collect::<LinkedList<_>>();
+    assert_ne!(packed, shorter);
+    assert_ne!(shorter, packed);
+}
+
+#[test]
+fn total_node_size_matches_len() {
+    let mut list = packed_linked_list![3; 1, 2, 3, 4, 5, 6];
+    assert_eq!(list.total_node_size(), list.len());
+
+    let mut cursor = list.cursor_mut_front();
+    // insert into a full node
+    cursor.insert_after(10);
+    cursor.move_next();
+    cursor.move_next();
+    // append to a full node
+    cursor.insert_after(11);
+    assert_eq!(list.total_node_size(), list.len());
+    assert_eq!(list, create_sized_list(&[1, 10, 2, 11, 3, 4, 5, 6]));
+
+    assert_eq!(list.pop_front(), Some(1));
+    assert_eq!(list.pop_back(), Some(6));
+    assert_eq!(list.total_node_size(), list.len());
+
+    list.push_front(0);
+    list.extract_if(|item| *item > 9).for_each(drop);
+    assert_eq!(list.total_node_size(), list.len());
+    assert_eq!(list, create_sized_list(&[0, 2, 3, 4, 5]));
+
+    while list.pop_front().is_some() {
+        assert_eq!(list.total_node_size(), list.len());
+    }
+    assert_eq!(list.total_node_size(), 0);
+}
+
+#[test]
+fn pop_front_keeps_offset() {
+    let mut list = packed_linked_list![4; 1, 2, 3, 4, 5, 6];
+    assert_eq!(list.pop_front(), Some(1));
+    assert_eq!(list.pop_front(), Some(2));
+    assert_eq!(list, create_sized_list(&[3, 4, 5, 6]));
+
+    // reuses the free space in front
+    list.push_front(2);
+    assert_eq!(list.cursor_front().get(), Some(&2));
+    assert_eq!(list.total_node_size(), list.len());
+
+    let mut list = packed_linked_list![4; 1, 2, 3];
+    list.pop_front();
+    // compacts the node to make space at the back
+    list.push_back(4);
+    list.push_back(5);
+    assert_eq!(list, create_sized_list(&[2, 3, 4, 5]));
+    assert_eq!(list.total_node_size(), 4);
+
+    let mut cursor = list.cursor_mut_front();
+    cursor.insert_after(10);
+    assert_eq!(list, create_sized_list(&[2, 10, 3, 4, 5]));
+    assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![2, 10, 3, 4, 5]);
+}
+
+#[test]
+fn drop_values() {
+    let mut list = packed_linked_list![4;
+        String::from("1"),
+        String::from("2"),
+        String::from("3"),
+        String::from("4"),
+        String::from("5"),
+    ];
+    assert_eq!(list.pop_front().as_deref(), Some("1"));
+    let mut iter = list.clone().into_iter();
+    assert_eq!(iter.next().as_deref(), Some("2"));
+}
+
+#[test]
+fn pop_back_drops_values() {
+    // this test is mostly useful under Miri, which detects leaked or double dropped values
+    for mut list in [
+        PackedLinkedList::<_, 4>::new(),
+        PackedLinkedList::<_, 4>::with_node_pool(),
+    ] {
+        list.extend((0..10).map(|i| i.to_string()));
+        list.pop_front();
+        for i in (1..10).rev() {
+            assert_eq!(list.pop_back(), Some(i.to_string()));
+            assert_eq!(list.total_node_size(), list.len());
+        }
+        assert_eq!(list.pop_back(), None);
+        assert!(list.is_empty());
+
+        // emptied nodes are reused by the pool
+        list.push_back(String::from("a"));
+        list.push_front(String::from("b"));
+        assert_eq!(list.pop_back().as_deref(), Some("a"));
+    }
+}
+
+#[test]
+fn dedup() {
+    let mut list = packed_linked_list![2; 1, 1, 2, 2, 2, 3, 1];
+    list.dedup();
+    assert_eq!(list, create_sized_list(&[1, 2, 3, 1]));
+    assert_eq!(list.len(), 4);
+    assert_eq!(list.total_node_size(), 4);
+
+    let mut list = create_sized_list::<_, 3>(&["a", "a", "a", "a", "a"].map(String::from));
+    list.dedup();
+    assert_eq!(list, create_sized_list(&[String::from("a")]));
+    assert_eq!(list.total_node_size(), 1);
+}
+
+#[test]
+fn dedup_panicking_eq() {
+    // this test is mostly useful under Miri, which detects leaked or double dropped values
+    #[derive(Debug, Clone)]
+    struct PanicOnEq(String);
+
+    impl PartialEq for PanicOnEq {
+        fn eq(&self, other: &Self) -> bool {
+            if other.0 == "panic" {
+                panic!("comparing {:?}", other.0);
+            }
+            self.0 == other.0
+        }
+    }
+
+    let values = ["a", "a", "b", "b", "c", "c", "panic", "d"].map(|s| PanicOnEq(s.to_string()));
+    let mut list = create_sized_list::<_, 4>(&values);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| list.dedup()));
+    assert!(result.is_err());
+
+    assert_eq!(
+        list.iter()
+            .map(|value| value.0.as_str())
+            .collect::<Vec<_>>(),
+        vec!["a", "b", "c", "panic", "d"]
+    );
+    assert_eq!(list.len(), 5);
+    assert_eq!(list.total_node_size(), list.len());
+}
+
+#[test]
+fn list_macro() {
+    let list = crate::packed_linked_list![2; 1, 2, 3];
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert_eq!(list.node_count(), 2);
+    let empty: PackedLinkedList<i32, 4> = crate::packed_linked_list![4];
+    assert_eq!(empty, PackedLinkedList::new());
+    let empty: PackedLinkedList<i32, 4> = crate::packed_linked_list![4;];
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn node_pool() {
+    let mut list = PackedLinkedList::<_, 2>::with_node_pool();
+    for i in 0..10 {
+        list.push_back(i.to_string());
+    }
+    while list.len() > 1 {
+        list.pop_front();
+    }
+    list.push_front(String::from("a"));
+    list.push_back(String::from("b"));
+    list.push_back(String::from("c"));
+    assert_eq!(list.pop_back().as_deref(), Some("c"));
+    assert_eq!(
+        list.iter().map(String::as_str).collect::<Vec<_>>(),
+        vec!["a", "9", "b"]
+    );
+    assert_eq!(list.total_node_size(), list.len());
+
+    let mut other = PackedLinkedList::<_, 2>::with_node_pool();
+    other.extend(list.iter().cloned());
+    other.pop_front();
+    other.pop_front();
+    // the pooled node is freed by the iterator
+    assert_eq!(other.into_iter().count(), 1);
+
+    // the list is dropped with nodes in the pool
+    while list.pop_back().is_some() {}
+    assert!(list.is_empty());
+}
+
+#[test]
+fn pop_front_if() {
+    let mut list = packed_linked_list![8; 1, 2, 3, 4];
+    let mut drained = Vec::new();
+    while let Some(value) = list.pop_front_if(|value| *value < 3) {
+        drained.push(value);
+    }
+    assert_eq!(drained, vec![1, 2]);
+    assert_eq!(list, packed_linked_list![8; 3, 4]);
+
+    assert_eq!(list.pop_front_if(|value| *value == 4), None);
+    assert_eq!(list, packed_linked_list![8; 3, 4]);
+
+    let mut empty = PackedLinkedList::<i32, 8>::new();
+    assert_eq!(empty.pop_front_if(|_| true), None);
+}
+
+#[test]
+fn iter_with_node_breaks() {
+    let mut list = packed_linked_list![3; 1, 2, 3, 4, 5, 6, 7];
+    list.pop_front();
+    let breaks = list
+        .iter_with_node_breaks()
+        .map(|(is_break, _)| is_break)
+        .collect::<Vec<_>>();
+    assert_eq!(breaks, vec![true, false, true, false, false, true]);
+
+    // running sums that reset at every node
+    let mut sum = 0;
+    for (is_break, value) in list.iter_mut_with_node_breaks() {
+        if is_break {
+            sum = 0;
+        }
+        sum += *value;
+        *value = sum;
+    }
+    assert_eq!(list, create_sized_list(&[2, 5, 4, 9, 15, 7]));
+    assert_eq!(
+        PackedLinkedList::<i32, 8>::new()
+            .iter_with_node_breaks()
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn iter_chain() {
+    let first = packed_linked_list![8; 1, 2];
+    let second = packed_linked_list![8; 3, 4];
+    assert_eq!(
+        first.iter_chain(&second).copied().collect::<Vec<_>>(),
+        vec![1, 2, 3, 4]
+    );
+
+    let empty = PackedLinkedList::<i32, 8>::new();
+    assert_eq!(
+        empty.iter_chain(&first).copied().collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+    assert_eq!(
+        first.iter_chain(&empty).copied().collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+}
+
+#[test]
+fn shrink_to_fit() {
+    let mut list = create_sized_list::<_, 4>(&(0..16).collect::<Vec<_>>());
+    assert_eq!(list.node_count(), 4);
+    // leave a hole in every node
+    let mut index = 0;
+    list.extract_if(|_| {
+        index += 1;
+        index % 4 != 0
+    })
+    .for_each(drop);
+    assert_eq!(list, packed_linked_list![4; 3, 7, 11, 15]);
+    assert_eq!(list.node_count(), 4);
+
+    list.shrink_to_fit();
+    assert_eq!(list.node_count(), 1);
+    assert_eq!(list, packed_linked_list![4; 3, 7, 11, 15]);
+    assert_eq!(list.total_node_size(), list.len());
+
+    let mut list = create_sized_list::<_, 4>(&(0..10).map(|i| i.to_string()).collect::<Vec<_>>());
+    list.pop_front();
+    list.pop_front();
+    list.push_front("a".to_string());
+    list.shrink_to_fit();
+    assert_eq!(list.node_count(), 3);
+    assert_eq!(
+        list.iter().cloned().collect::<Vec<_>>(),
+        vec!["a", "2", "3", "4", "5", "6", "7", "8", "9"]
+    );
+    list.push_back("10".to_string());
+    assert_eq!(list.node_count(), 3);
+    assert_eq!(list.len(), 10);
+}
+
+#[test]
+fn for_each_chunk_mut() {
+    let values = (0..20).collect::<Vec<_>>();
+    let mut list = create_sized_list::<_, 4>(&values);
+    list.pop_front();
+
+    let mut chunk_lens = Vec::new();
+    list.for_each_chunk_mut(|chunk| {
+        chunk_lens.push(chunk.len());
+        chunk.iter_mut().for_each(|value| *value *= 2);
+    });
+    assert_eq!(chunk_lens, vec![3, 4, 4, 4, 4]);
+
+    let mut expected = create_sized_list::<_, 4>(&values);
+    expected.pop_front();
+    expected.iter_mut().for_each(|value| *value *= 2);
+    assert_eq!(list, expected);
+}
+
+#[test]
+fn try_push() {
+    let mut list = packed_linked_list![8; 2];
+    assert!(list.try_push_back(3).is_ok());
+    assert!(list.try_push_front(1).is_ok());
+    assert_eq!(list, packed_linked_list![8; 1, 2, 3]);
+
+    let mut list = PackedLinkedList::<String, 8>::new();
+    for i in 0..20 {
+        list.try_push_back(i.to_string()).unwrap();
+        list.try_push_front(i.to_string()).unwrap();
+    }
+    assert_eq!(list.len(), 40);
+}
+
+#[test]
+fn bulk_extend() {
+    let values = (0..50).map(|i| i.to_string()).collect::<Vec<_>>();
+    let mut pushed = PackedLinkedList::<_, 8>::new();
+    values
+        .iter()
+        .cloned()
+        .for_each(|value| pushed.push_back(value));
+    let collected = values.iter().cloned().collect::<PackedLinkedList<_, 8>>();
+    assert_eq!(collected, pushed);
+    assert_eq!(collected.node_count(), pushed.node_count());
+    assert_eq!(collected.total_node_size(), 50);
+
+    let mut list = packed_linked_list![8; 1, 2, 3];
+    list.pop_front();
+    list.extend(4..20);
+    assert_eq!(list, (2..20).collect::<PackedLinkedList<_, 8>>());
+    assert_eq!(list.total_node_size(), list.len());
+    list.extend(Vec::new());
+    assert_eq!(list.node_count(), 3);
+}
+
+#[test]
+#[cfg(feature = "metrics")]
+fn metrics() {
+    let values = (0..100).collect::<Vec<_>>();
+    let collected = values.iter().copied().collect::<PackedLinkedList<_, 8>>();
+    let mut pushed = PackedLinkedList::<_, 8>::new();
+    for value in &values {
+        pushed.push_front(*value);
+    }
+    // filling the nodes in bulk doesn't save any allocations, pushing fills every node before allocating
+    // the next one as well. it only saves the bookkeeping for every single value
+    assert_eq!(collected.allocations(), 13);
+    assert_eq!(pushed.allocations(), 13);
+
+    let mut list = PackedLinkedList::<_, 8>::new();
+    let mut pooled = PackedLinkedList::<_, 8>::with_node_pool();
+    for i in 0..100 {
+        list.push_back(i);
+        list.pop_front();
+        pooled.push_back(i);
+        pooled.pop_front();
+    }
+    assert_eq!(list.allocations(), 100);
+    assert_eq!(list.deallocations(), 100);
+    assert_eq!(pooled.allocations(), 1);
+    assert_eq!(pooled.deallocations(), 0);
+    pooled.clear_node_pool();
+    assert_eq!(pooled.deallocations(), 1);
+}
+
+#[test]
+fn from_vec() {
+    // this test is mostly useful under Miri, which detects leaked or double dropped values
+    let values = (0..10).map(|i| i.to_string()).collect::<Vec<_>>();
+    let list = PackedLinkedList::<_, 4>::from_vec(values.clone());
+    assert_eq!(list.iter().cloned().collect::<Vec<_>>(), values);
+    assert_eq!(list.len(), 10);
+    assert_eq!(list.node_count(), 3);
+    assert_eq!(list.total_node_size(), 10);
+
+    let list = PackedLinkedList::<String, 4>::from_vec(Vec::new());
+    assert!(list.is_empty());
+    assert_eq!(list.node_count(), 0);
+}
+
+#[test]
+fn repair_endpoints() {
+    let reference = create_sized_list::<_, 4>(&(0..10).collect::<Vec<_>>());
+    let mut list = reference.clone();
+    // corrupt the bookkeeping of the list
+    list.last = list.first;
+    list.len = 3;
+    unsafe { list.last.unwrap().as_mut().next.unwrap().as_mut().prev = None };
+
+    list.repair_endpoints();
+    assert_eq!(list.len(), 10);
+    assert_eq!(list, reference);
+    assert_eq!(list.pop_back(), Some(9));
+    assert_eq!(list.cursor_back().get(), Some(&8));
+
+    let mut empty = PackedLinkedList::<i32, 8>::new();
+    empty.repair_endpoints();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn ptr_eq() {
+    let list = packed_linked_list![8; 1, 2, 3];
+    let clone = list.clone();
+    assert!(list.ptr_eq(&list));
+    assert!(!list.ptr_eq(&clone));
+    assert_eq!(list, clone);
+
+    let empty = PackedLinkedList::<i32, 8>::new();
+    assert!(empty.ptr_eq(&empty));
+    assert!(!empty.ptr_eq(&PackedLinkedList::<i32, 8>::new()));
+    assert!(!empty.ptr_eq(&list));
+}
+
+#[test]
+fn eq_fast() {
+    let values = (0..20).collect::<Vec<_>>();
+    let list = create_sized_list::<_, 4>(&values);
+    assert!(list.eq_fast(&list.clone()));
+
+    // same values, but the first node only contains two values, so all node sizes differ
+    let mut packed = create_sized_list::<_, 4>(&(-2..20).collect::<Vec<_>>());
+    packed.pop_front();
+    packed.pop_front();
+    assert_ne!(list.node_count(), packed.node_count());
+    assert!(list.eq_fast(&packed));
+    assert!(packed.eq_fast(&list));
+
+    *packed.cursor_mut_back().get_mut().unwrap() = 100;
+    assert!(!list.eq_fast(&packed));
+    assert!(!packed.eq_fast(&list));
+    let mut shorter = list.clone();
+    shorter.pop_back();
+    assert!(!list.eq_fast(&shorter));
+    let mut changed = list.clone();
+    *changed.iter_mut().next().unwrap() = 100;
+    assert!(!list.eq_fast(&changed));
+}
+
+#[test]
+fn count_in_range() {
+    let list = (1..=10).collect::<PackedLinkedList<_, 8>>();
+    assert_eq!(list.count_in_range(3..=6), 4);
+    assert_eq!(list.count_in_range(3..6), 3);
+    assert_eq!(list.count_in_range(..3), 2);
+    assert_eq!(list.count_in_range(8..), 3);
+    assert_eq!(list.count_in_range(..), 10);
+    assert_eq!(list.count_in_range(20..30), 0);
+    assert_eq!(PackedLinkedList::<i32, 8>::new().count_in_range(..), 0);
+}
+
+#[test]
+fn dedup_iter() {
+    let list = packed_linked_list![8; 1, 1, 2, 3, 3, 1];
+    assert_eq!(list.dedup_iter().collect::<Vec<_>>(), vec![&1, &2, &3, &1]);
+    assert_eq!(list, packed_linked_list![8; 1, 1, 2, 3, 3, 1]);
+    assert_eq!(PackedLinkedList::<i32, 8>::new().dedup_iter().next(), None);
+}
+
+#[test]
+fn partition() {
+    let list = create_sized_list::<_, 4>(&(1..=10).collect::<Vec<_>>());
+    let (even, odd) = list.partition(|item| item % 2 == 0);
+    assert_eq!(even, create_sized_list(&[2, 4, 6, 8, 10]));
+    assert_eq!(odd, create_sized_list(&[1, 3, 5, 7, 9]));
+    assert_eq!(even.len(), 5);
+    assert_eq!(even.total_node_size(), 5);
+    assert_eq!(odd.len(), 5);
+    assert_eq!(odd.total_node_size(), 5);
+
+    let list = packed_linked_list![4; 1, 2, 3, 10, 11, 12, 13, 14, 4, 15];
+    let mut calls = 0;
+    let (big, small) = list.partition(|item| {
+        calls += 1;
+        *item >= 10
+    });
+    assert_eq!(calls, 10);
+    assert_eq!(big, create_sized_list(&[10, 11, 12, 13, 14, 15]));
+    assert_eq!(small, create_sized_list(&[1, 2, 3, 4]));
+    assert_eq!(big.node_count(), 2);
+    assert_eq!(small.node_count(), 1);
+
+    let (matching, rest) = PackedLinkedList::<i32, 8>::new().partition(|_| true);
+    assert!(matching.is_empty());
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn duplicates() {
+    let list = packed_linked_list![8; 1, 2, 2, 3, 3, 3, 4];
+    assert_eq!(list.duplicates(), vec![&2, &3]);
+    let list = packed_linked_list![2; 2, 1, 1, 2, 5];
+    assert_eq!(list.duplicates(), vec![&2, &1]);
+    assert!(packed_linked_list![8; 1, 2, 3].duplicates().is_empty());
+}
+
+#[test]
+fn kth_largest() {
+    let list = packed_linked_list![4; 3, 1, 4, 1, 5, 9, 2, 6];
+    assert_eq!(list.kth_largest(1), Some(9));
+    assert_eq!(list.kth_largest(3), Some(5));
+    assert_eq!(list.kth_largest(7), Some(1));
+    assert_eq!(list.kth_largest(8), Some(1));
+    assert_eq!(list.kth_largest(9), None);
+    assert_eq!(list.kth_largest(0), None);
+}
+
+#[test]
+fn into_unpacked_iter() {
+    let values = (0..20).map(|i| i.to_string()).collect::<Vec<_>>();
+    let list = create_sized_list::<_, 4>(&values);
+    let unpacked = list.into_unpacked_iter().collect::<LinkedList<_>>();
+    assert_eq!(unpacked, values.iter().cloned().collect::<LinkedList<_>>());
+
+    // the remaining values are dropped with the iterator
+    let mut iter = create_sized_list::<_, 4>(&values).into_unpacked_iter();
+    assert_eq!(iter.nth(5), Some("5".to_string()));
+}
+
+#[test]
+fn get() {
+    let values = (0..10).collect::<Vec<_>>();
+    let mut list = create_sized_list::<_, 4>(&values);
+    // the first node has free space at the start
+    list.pop_front();
+    for (index, value) in values[1..].iter().enumerate() {
+        assert_eq!(list.get(index), Some(value));
+    }
+    assert_eq!(list.get(9), None);
+    assert_eq!(list.get(100), None);
+    assert_eq!(PackedLinkedList::<i32, 8>::new().get(0), None);
+}
+
+#[test]
+fn try_into_array() {
+    let array: [i32; 3] = <[i32; 3]>::try_from(packed_linked_list![8; 1, 2, 3]).unwrap();
+    assert_eq!(array, [1, 2, 3]);
+
+    let list = <[i32; 4]>::try_from(packed_linked_list![8; 1, 2, 3]).unwrap_err();
+    assert_eq!(list, packed_linked_list![8; 1, 2, 3]);
 }
 
+/// Creates a list from a slice, for values that are not written out or when the node size is inferred,
+/// [packed_linked_list!] needs the node size and the values spelled out
 fn create_sized_list<T: Clone, const COUNT: usize>(iter: &[T]) -> PackedLinkedList<T, COUNT> {
-    iter.into_iter().cloned().collect()
+    iter.iter().cloned().collect()
 }